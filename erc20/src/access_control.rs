@@ -0,0 +1,39 @@
+//! Implementation of role-based access control.
+use alloc::{string::String, vec::Vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{bytesrepr::ToBytes, URef};
+
+use crate::{constants::ROLES_KEY_NAME, detail, Address};
+
+#[inline]
+pub(crate) fn roles_uref() -> URef {
+    detail::get_uref(ROLES_KEY_NAME)
+}
+
+/// Creates a dictionary item key for a (role, address) pair.
+fn make_dictionary_item_key(role: &str, address: Address) -> String {
+    let mut preimage = Vec::new();
+    preimage.append(&mut String::from(role).to_bytes().unwrap_or_revert());
+    preimage.append(&mut address.to_bytes().unwrap_or_revert());
+
+    let key_bytes = runtime::blake2b(&preimage);
+    hex::encode(&key_bytes)
+}
+
+/// Grants or revokes `role` of `address`.
+pub(crate) fn write_role_to(roles_uref: URef, role: &str, address: Address, granted: bool) {
+    let dictionary_item_key = make_dictionary_item_key(role, address);
+    storage::dictionary_put(roles_uref, &dictionary_item_key, granted)
+}
+
+/// Checks whether `address` has `role`.
+pub(crate) fn read_role_from(roles_uref: URef, role: &str, address: Address) -> bool {
+    let dictionary_item_key = make_dictionary_item_key(role, address);
+    storage::dictionary_get(roles_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
@@ -14,11 +14,16 @@ pub(crate) fn allowances_uref() -> URef {
     detail::get_uref(ALLOWANCES_KEY_NAME)
 }
 
-/// Creates a dictionary item key for an (owner, spender) pair.
-fn make_dictionary_item_key(owner: Address, spender: Address) -> String {
+/// Suffix which distinguishes the dictionary item key of an allowance's expiry from the key of
+/// the allowance itself.
+const EXPIRY_KEY_SUFFIX: &[u8] = b"expiry";
+
+/// Creates a dictionary item key for an (owner, spender) pair, followed by `suffix`.
+fn make_dictionary_item_key(owner: Address, spender: Address, suffix: &[u8]) -> String {
     let mut preimage = Vec::new();
     preimage.append(&mut owner.to_bytes().unwrap_or_revert());
     preimage.append(&mut spender.to_bytes().unwrap_or_revert());
+    preimage.extend_from_slice(suffix);
 
     let key_bytes = runtime::blake2b(&preimage);
     hex::encode(&key_bytes)
@@ -31,14 +36,48 @@ pub(crate) fn write_allowance_to(
     spender: Address,
     amount: U256,
 ) {
-    let dictionary_item_key = make_dictionary_item_key(owner, spender);
+    let dictionary_item_key = make_dictionary_item_key(owner, spender, &[]);
     storage::dictionary_put(allowances_uref, &dictionary_item_key, amount)
 }
 
 /// Reads an allowance for a owner and spender
 pub(crate) fn read_allowance_from(allowances_uref: URef, owner: Address, spender: Address) -> U256 {
-    let dictionary_item_key = make_dictionary_item_key(owner, spender);
+    let dictionary_item_key = make_dictionary_item_key(owner, spender, &[]);
     storage::dictionary_get(allowances_uref, &dictionary_item_key)
         .unwrap_or_revert()
         .unwrap_or_default()
 }
+
+/// Writes the block time at which an allowance for owner and spender expires, or `None` if it
+/// never does.
+///
+/// Expiries are stored in the allowances dictionary next to the allowances themselves.
+pub(crate) fn write_allowance_expiry_to(
+    allowances_uref: URef,
+    owner: Address,
+    spender: Address,
+    expires_at: Option<u64>,
+) {
+    let dictionary_item_key = make_dictionary_item_key(owner, spender, EXPIRY_KEY_SUFFIX);
+    storage::dictionary_put(allowances_uref, &dictionary_item_key, expires_at)
+}
+
+/// Reads the block time at which an allowance for owner and spender expires.
+///
+/// If the allowance was never given an expiry, then `None` is returned.
+pub(crate) fn read_allowance_expiry_from(
+    allowances_uref: URef,
+    owner: Address,
+    spender: Address,
+) -> Option<u64> {
+    let dictionary_item_key = make_dictionary_item_key(owner, spender, EXPIRY_KEY_SUFFIX);
+    storage::dictionary_get(allowances_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .flatten()
+}
+
+/// Returns `true` if an allowance expiring at `expires_at` can no longer be spent.
+pub(crate) fn is_expired(expires_at: u64) -> bool {
+    let blocktime: u64 = runtime::get_blocktime().into();
+    blocktime >= expires_at
+}
@@ -1,30 +1,55 @@
 //! Implementation of balances.
-use alloc::string::String;
+use alloc::{collections::BTreeMap, vec::Vec};
 
 use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
-use casper_types::{bytesrepr::ToBytes, URef, U256};
-
-use crate::{constants::BALANCES_KEY_NAME, detail, error::Error, Address};
-
-/// Creates a dictionary item key for a dictionary item.
-#[inline]
-fn make_dictionary_item_key(owner: Address) -> String {
-    let preimage = owner.to_bytes().unwrap_or_revert();
-    // NOTE: As for now dictionary item keys are limited to 64 characters only. Instead of using
-    // hashing (which will effectively hash a hash) we'll use base64. Preimage is about 33 bytes for
-    // both Address variants, and approximated base64-encoded length will be 4 * (33 / 3) ~ 44
-    // characters.
-    // Even if the preimage increased in size we still have extra space but even in case of much
-    // larger preimage we can switch to base85 which has ratio of 4:5.
-    base64::encode(&preimage)
-}
+use casper_types::{URef, U256};
+
+use crate::{
+    blocklist,
+    constants::BALANCES_KEY_NAME,
+    detail::{self, make_dictionary_item_key},
+    error::Error,
+    holders::Holders,
+    rebasing::Shares,
+    snapshots::Snapshots,
+    transfer_fee::{self, Fee},
+    Address,
+};
 
 pub(crate) fn get_balances_uref() -> URef {
     detail::get_uref(BALANCES_KEY_NAME)
 }
 
+/// Optional features which follow every change of a balance.
+#[derive(Clone, Copy)]
+pub(crate) struct BalanceTrackers {
+    /// Snapshots of balances, if the contract keeps them.
+    pub(crate) snapshots: Option<Snapshots>,
+    /// Index of holders, if the contract keeps one.
+    pub(crate) holders: Option<Holders>,
+}
+
 /// Writes token balance of a specified account into a dictionary.
-pub(crate) fn write_balance_to(balances_uref: URef, address: Address, amount: U256) {
+///
+/// If the contract keeps `snapshots`, the previous balance is checkpointed first. If it keeps an
+/// index of `holders`, the address is added to it or removed from it as its balance becomes
+/// non-zero or zero.
+pub(crate) fn write_balance_to(
+    balances_uref: URef,
+    trackers: BalanceTrackers,
+    address: Address,
+    amount: U256,
+) {
+    let BalanceTrackers { snapshots, holders } = trackers;
+    if snapshots.is_some() || holders.is_some() {
+        let balance = read_balance_from(balances_uref, address);
+        if let Some(snapshots) = snapshots {
+            snapshots.checkpoint_balance(address, balance);
+        }
+        if let Some(holders) = holders {
+            holders.update(address, balance, amount);
+        }
+    }
     let dictionary_item_key = make_dictionary_item_key(address);
     storage::dictionary_put(balances_uref, &dictionary_item_key, amount);
 }
@@ -42,18 +67,31 @@ pub(crate) fn read_balance_from(balances_uref: URef, address: Address) -> U256 {
 
 /// Transfer tokens from the `sender` to the `recipient`.
 ///
+/// If the contract charges a transfer fee, it is taken out of `amount` and given to the treasury,
+/// and returned so that the caller can account for it. If the balances are stored as `shares`,
+/// amounts of tokens are converted into shares first.
+///
 /// This function should not be used directly by contract's entrypoint as it does not validate the
-/// sender.
+/// sender. It does however reject transfers from or to a frozen address.
 pub(crate) fn transfer_balance(
     balances_uref: URef,
+    frozen_uref: URef,
+    shares: Option<Shares>,
+    trackers: BalanceTrackers,
     sender: Address,
     recipient: Address,
     amount: U256,
-) -> Result<(), Error> {
+) -> Result<Option<Fee>, Error> {
+    blocklist::ensure_not_frozen(frozen_uref, &[sender, recipient])?;
+
     if sender == recipient || amount.is_zero() {
-        return Ok(());
+        return Ok(None);
     }
 
+    let fee = transfer_fee::transfer_fee(sender, recipient, amount)?;
+    let fee_amount = fee.as_ref().map(|fee| fee.amount).unwrap_or_default();
+    let (amount, fee_amount) = to_stored_amounts(shares, amount, fee_amount)?;
+
     let new_sender_balance = {
         let sender_balance = read_balance_from(balances_uref, sender);
         sender_balance
@@ -64,12 +102,120 @@ pub(crate) fn transfer_balance(
     let new_recipient_balance = {
         let recipient_balance = read_balance_from(balances_uref, recipient);
         recipient_balance
-            .checked_add(amount)
+            .checked_add(amount - fee_amount)
             .ok_or(Error::Overflow)?
     };
 
-    write_balance_to(balances_uref, sender, new_sender_balance);
-    write_balance_to(balances_uref, recipient, new_recipient_balance);
+    // Treasury is never the sender nor the recipient of a transfer it is paid a fee for.
+    let new_treasury_balance = match &fee {
+        Some(fee) => {
+            let treasury_balance = read_balance_from(balances_uref, fee.treasury);
+            let new_treasury_balance = treasury_balance
+                .checked_add(fee_amount)
+                .ok_or(Error::Overflow)?;
+            Some((fee.treasury, new_treasury_balance))
+        }
+        None => None,
+    };
+
+    write_balance_to(balances_uref, trackers, sender, new_sender_balance);
+    write_balance_to(balances_uref, trackers, recipient, new_recipient_balance);
+    if let Some((treasury, new_treasury_balance)) = new_treasury_balance {
+        write_balance_to(balances_uref, trackers, treasury, new_treasury_balance);
+    }
+
+    Ok(fee)
+}
+
+/// Converts an amount of tokens taken from a sender, and the fee taken out of it, into the units
+/// in which balances are stored.
+///
+/// Amount taken from the sender rounds up, and the fee rounds down, so that neither the sender nor
+/// the treasury gains from rounding.
+fn to_stored_amounts(
+    shares: Option<Shares>,
+    amount: U256,
+    fee_amount: U256,
+) -> Result<(U256, U256), Error> {
+    match shares {
+        Some(shares) => Ok((
+            shares.to_shares_rounding_up(amount)?,
+            shares.to_shares(fee_amount)?,
+        )),
+        None => Ok((amount, fee_amount)),
+    }
+}
+
+/// Transfer tokens from the `sender` to each of the `recipients`, in the respective `amounts`.
+///
+/// The sender is debited once with the sum of all amounts, and no balance is written unless every
+/// transfer succeeds. Both lists are expected to be of the same length. Transfer fees charged for
+/// each of the transfers are returned in the same order.
+///
+/// This function should not be used directly by contract's entrypoint as it does not validate the
+/// sender.
+pub(crate) fn transfer_balances(
+    balances_uref: URef,
+    frozen_uref: URef,
+    shares: Option<Shares>,
+    trackers: BalanceTrackers,
+    sender: Address,
+    recipients: &[Address],
+    amounts: &[U256],
+) -> Result<Vec<Option<Fee>>, Error> {
+    blocklist::ensure_not_frozen(frozen_uref, &[sender])?;
+    blocklist::ensure_not_frozen(frozen_uref, recipients)?;
+
+    let mut fees = Vec::with_capacity(recipients.len());
+    let mut stored_amounts = Vec::with_capacity(recipients.len());
+
+    for (recipient, amount) in recipients.iter().zip(amounts) {
+        let fee = transfer_fee::transfer_fee(sender, *recipient, *amount)?;
+        let fee_amount = fee.as_ref().map(|fee| fee.amount).unwrap_or_default();
+        stored_amounts.push(to_stored_amounts(shares, *amount, fee_amount)?);
+        fees.push(fee);
+    }
+
+    let total_amount = stored_amounts
+        .iter()
+        .try_fold(U256::zero(), |total, (amount, _)| {
+            total.checked_add(*amount)
+        })
+        .ok_or(Error::Overflow)?;
+
+    let mut new_balances = BTreeMap::new();
+
+    let new_sender_balance = {
+        let sender_balance = read_balance_from(balances_uref, sender);
+        sender_balance
+            .checked_sub(total_amount)
+            .ok_or(Error::InsufficientBalance)?
+    };
+    new_balances.insert(sender, new_sender_balance);
+
+    for ((recipient, (amount, fee_amount)), fee) in recipients.iter().zip(stored_amounts).zip(&fees)
+    {
+        // A recipient may appear more than once, or be the sender itself.
+        let balance = new_balances
+            .entry(*recipient)
+            .or_insert_with(|| read_balance_from(balances_uref, *recipient));
+        *balance = balance
+            .checked_add(amount - fee_amount)
+            .ok_or(Error::Overflow)?;
+
+        if let Some(fee) = fee {
+            let treasury_balance = new_balances
+                .entry(fee.treasury)
+                .or_insert_with(|| read_balance_from(balances_uref, fee.treasury));
+            *treasury_balance = treasury_balance
+                .checked_add(fee_amount)
+                .ok_or(Error::Overflow)?;
+        }
+    }
+
+    for (address, balance) in new_balances {
+        write_balance_to(balances_uref, trackers, address, balance);
+    }
 
-    Ok(())
+    Ok(fees)
 }
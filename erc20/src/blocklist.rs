@@ -0,0 +1,41 @@
+//! Implementation of the blocklist of frozen addresses.
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::URef;
+
+use crate::{
+    constants::FROZEN_KEY_NAME,
+    detail::{self, make_dictionary_item_key},
+    error::Error,
+    Address,
+};
+
+#[inline]
+pub(crate) fn frozen_uref() -> URef {
+    detail::get_uref(FROZEN_KEY_NAME)
+}
+
+/// Freezes or unfreezes `address`.
+pub(crate) fn write_frozen_to(frozen_uref: URef, address: Address, frozen: bool) {
+    let dictionary_item_key = make_dictionary_item_key(address);
+    storage::dictionary_put(frozen_uref, &dictionary_item_key, frozen)
+}
+
+/// Checks whether `address` is frozen.
+pub(crate) fn read_frozen_from(frozen_uref: URef, address: Address) -> bool {
+    let dictionary_item_key = make_dictionary_item_key(address);
+    storage::dictionary_get(frozen_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Ensures that none of `addresses` is frozen.
+pub(crate) fn ensure_not_frozen(frozen_uref: URef, addresses: &[Address]) -> Result<(), Error> {
+    if addresses
+        .iter()
+        .any(|address| read_frozen_from(frozen_uref, *address))
+    {
+        Err(Error::AccountFrozen)
+    } else {
+        Ok(())
+    }
+}
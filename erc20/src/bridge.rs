@@ -0,0 +1,265 @@
+//! Implementation of the bridge, which mints tokens for transfers made on other chains and burns
+//! tokens sent back to them.
+//!
+//! Every inbound transfer is identified by the id of its source chain and the hash of its source
+//! transaction, and is recorded as a [`BridgeTransfer`] in the `bridge_transfers` dictionary once
+//! it is minted, so that it can never be minted twice. The record is stored under the hex-encoded
+//! blake2b hash of the serialized chain id followed by the serialized transaction hash, as a
+//! `(Key, U256, u64)` tuple of the recipient, the amount and the block time.
+//!
+//! Every outbound transfer is stored as a [`BridgeRequest`] in the `bridge_requests` dictionary
+//! under consecutive indices, with the number of requests stored under `bridge_request_count`, so
+//! that relayers can process them in order. Requests are stored as a
+//! `((Key, U256), (u64, Vec<u8>), u64)` tuple of the sender and the amount, the destination chain
+//! id and address, and the block time. Amounts sent to each chain are limited by the minimum
+//! and maximum stored for it in the `bridge_limits` dictionary.
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::{self, Bytes, FromBytes, ToBytes},
+    contracts::NamedKeys,
+    CLType, CLTyped, Key, URef, U256,
+};
+
+use crate::{
+    constants::{
+        BRIDGE_LIMITS_KEY_NAME, BRIDGE_REQUESTS_KEY_NAME, BRIDGE_REQUEST_COUNT_KEY_NAME,
+        BRIDGE_TRANSFERS_KEY_NAME,
+    },
+    detail,
+    error::Error,
+    Address,
+};
+
+/// Record of a transfer made on another chain, which was minted on this chain.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct BridgeTransfer {
+    /// Address the tokens were minted to.
+    pub recipient: Address,
+    /// Amount of tokens minted.
+    pub amount: U256,
+    /// Block time of the mint, in milliseconds.
+    pub block_time: u64,
+}
+
+impl CLTyped for BridgeTransfer {
+    fn cl_type() -> CLType {
+        // Serialized field by field exactly like this tuple, so clients can read it as one.
+        <(Address, U256, u64)>::cl_type()
+    }
+}
+
+impl ToBytes for BridgeTransfer {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.recipient.to_bytes()?);
+        result.append(&mut self.amount.to_bytes()?);
+        result.append(&mut self.block_time.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.recipient.serialized_length()
+            + self.amount.serialized_length()
+            + self.block_time.serialized_length()
+    }
+}
+
+impl FromBytes for BridgeTransfer {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (recipient, bytes) = Address::from_bytes(bytes)?;
+        let (amount, bytes) = U256::from_bytes(bytes)?;
+        let (block_time, bytes) = u64::from_bytes(bytes)?;
+        let bridge_transfer = BridgeTransfer {
+            recipient,
+            amount,
+            block_time,
+        };
+        Ok((bridge_transfer, bytes))
+    }
+}
+
+#[inline]
+pub(crate) fn bridge_transfers_uref() -> URef {
+    detail::get_uref(BRIDGE_TRANSFERS_KEY_NAME)
+}
+
+/// Creates a dictionary item key for a transfer made on another chain.
+fn make_dictionary_item_key(source_chain_id: u64, source_tx_hash: &Bytes) -> String {
+    let mut preimage = source_chain_id.to_bytes().unwrap_or_revert();
+    preimage.append(&mut source_tx_hash.to_bytes().unwrap_or_revert());
+
+    let key_bytes = runtime::blake2b(&preimage);
+    hex::encode(&key_bytes)
+}
+
+/// Records a minted bridge transfer.
+pub(crate) fn write_bridge_transfer_to(
+    bridge_transfers_uref: URef,
+    source_chain_id: u64,
+    source_tx_hash: &Bytes,
+    bridge_transfer: BridgeTransfer,
+) {
+    let dictionary_item_key = make_dictionary_item_key(source_chain_id, source_tx_hash);
+    storage::dictionary_put(bridge_transfers_uref, &dictionary_item_key, bridge_transfer)
+}
+
+/// Reads the record of a bridge transfer.
+///
+/// If the transfer has not been minted yet, then `None` is returned.
+pub(crate) fn read_bridge_transfer_from(
+    bridge_transfers_uref: URef,
+    source_chain_id: u64,
+    source_tx_hash: &Bytes,
+) -> Option<BridgeTransfer> {
+    let dictionary_item_key = make_dictionary_item_key(source_chain_id, source_tx_hash);
+    storage::dictionary_get(bridge_transfers_uref, &dictionary_item_key).unwrap_or_revert()
+}
+
+/// Request to send tokens burned on this chain to an address on another chain.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct BridgeRequest {
+    /// Address the tokens were burned from.
+    pub sender: Address,
+    /// Amount of tokens burned.
+    pub amount: U256,
+    /// Id of the chain the tokens are sent to.
+    pub destination_chain_id: u64,
+    /// Address on the destination chain which receives the tokens, in the format of that chain.
+    pub destination_address: Vec<u8>,
+    /// Block time of the request, in milliseconds.
+    pub block_time: u64,
+}
+
+impl CLTyped for BridgeRequest {
+    fn cl_type() -> CLType {
+        // Tuples have at most three elements, so the fields are nested in pairs. Nesting does not
+        // change the serialization, which is the fields one after another.
+        <((Address, U256), (u64, Vec<u8>), u64)>::cl_type()
+    }
+}
+
+impl ToBytes for BridgeRequest {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.sender.to_bytes()?);
+        result.append(&mut self.amount.to_bytes()?);
+        result.append(&mut self.destination_chain_id.to_bytes()?);
+        result.append(&mut self.destination_address.to_bytes()?);
+        result.append(&mut self.block_time.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.sender.serialized_length()
+            + self.amount.serialized_length()
+            + self.destination_chain_id.serialized_length()
+            + self.destination_address.serialized_length()
+            + self.block_time.serialized_length()
+    }
+}
+
+impl FromBytes for BridgeRequest {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (sender, bytes) = Address::from_bytes(bytes)?;
+        let (amount, bytes) = U256::from_bytes(bytes)?;
+        let (destination_chain_id, bytes) = u64::from_bytes(bytes)?;
+        let (destination_address, bytes) = Vec::<u8>::from_bytes(bytes)?;
+        let (block_time, bytes) = u64::from_bytes(bytes)?;
+        let bridge_request = BridgeRequest {
+            sender,
+            amount,
+            destination_chain_id,
+            destination_address,
+            block_time,
+        };
+        Ok((bridge_request, bytes))
+    }
+}
+
+/// Ensures that `amount` can be sent to `destination_chain_id`.
+pub(crate) fn ensure_within_limits(destination_chain_id: u64, amount: U256) -> Result<(), Error> {
+    let bridge_limits_uref = detail::get_uref(BRIDGE_LIMITS_KEY_NAME);
+    let limits: Option<(U256, U256)> =
+        storage::dictionary_get(bridge_limits_uref, &destination_chain_id.to_string())
+            .unwrap_or_revert();
+    match limits {
+        Some((_, maximum)) if maximum.is_zero() => Err(Error::UnsupportedChain),
+        Some((minimum, maximum)) if amount < minimum || amount > maximum => {
+            Err(Error::BridgeAmountOutOfRange)
+        }
+        Some(_) => Ok(()),
+        None => Err(Error::UnsupportedChain),
+    }
+}
+
+/// Writes the minimum and maximum amount which can be sent to `destination_chain_id`.
+pub(crate) fn write_limits(destination_chain_id: u64, minimum: U256, maximum: U256) {
+    let bridge_limits_uref = detail::get_uref(BRIDGE_LIMITS_KEY_NAME);
+    storage::dictionary_put(
+        bridge_limits_uref,
+        &destination_chain_id.to_string(),
+        (minimum, maximum),
+    );
+}
+
+/// Reads the number of bridge requests made so far.
+pub(crate) fn read_request_count() -> u64 {
+    detail::read_from(BRIDGE_REQUEST_COUNT_KEY_NAME)
+}
+
+/// Stores `bridge_request` under the next free index, and returns that index.
+pub(crate) fn write_request(bridge_request: BridgeRequest) -> Result<u64, Error> {
+    let bridge_requests_uref = detail::get_uref(BRIDGE_REQUESTS_KEY_NAME);
+    let bridge_request_count_uref = detail::get_uref(BRIDGE_REQUEST_COUNT_KEY_NAME);
+    let index: u64 = storage::read(bridge_request_count_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    let new_request_count = index.checked_add(1).ok_or(Error::Overflow)?;
+    storage::dictionary_put(bridge_requests_uref, &index.to_string(), bridge_request);
+    storage::write(bridge_request_count_uref, new_request_count);
+    Ok(index)
+}
+
+/// Reads the bridge request stored under `index`, if there is one.
+pub(crate) fn read_request(index: u64) -> Option<BridgeRequest> {
+    let bridge_requests_uref = detail::get_uref(BRIDGE_REQUESTS_KEY_NAME);
+    storage::dictionary_get(bridge_requests_uref, &index.to_string()).unwrap_or_revert()
+}
+
+/// Sets up the storage of inbound transfers and outbound requests, with no chain supported yet.
+pub(crate) fn install(named_keys: &mut NamedKeys) {
+    let bridge_transfers_uref =
+        storage::new_dictionary(BRIDGE_TRANSFERS_KEY_NAME).unwrap_or_revert();
+    let bridge_requests_uref = storage::new_dictionary(BRIDGE_REQUESTS_KEY_NAME).unwrap_or_revert();
+    let bridge_limits_uref = storage::new_dictionary(BRIDGE_LIMITS_KEY_NAME).unwrap_or_revert();
+    let bridge_request_count_uref = storage::new_uref(0u64).into_read_write();
+
+    runtime::remove_key(BRIDGE_TRANSFERS_KEY_NAME);
+    runtime::remove_key(BRIDGE_REQUESTS_KEY_NAME);
+    runtime::remove_key(BRIDGE_LIMITS_KEY_NAME);
+
+    named_keys.insert(
+        BRIDGE_TRANSFERS_KEY_NAME.to_string(),
+        Key::from(bridge_transfers_uref),
+    );
+    named_keys.insert(
+        BRIDGE_REQUESTS_KEY_NAME.to_string(),
+        Key::from(bridge_requests_uref),
+    );
+    named_keys.insert(
+        BRIDGE_LIMITS_KEY_NAME.to_string(),
+        Key::from(bridge_limits_uref),
+    );
+    named_keys.insert(
+        BRIDGE_REQUEST_COUNT_KEY_NAME.to_string(),
+        Key::from(bridge_request_count_uref),
+    );
+}
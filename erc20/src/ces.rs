@@ -0,0 +1,209 @@
+//! Implementation of the Casper Event Standard (CES).
+//!
+//! Events are stored in the `__events` dictionary under consecutive indices, and each of them is
+//! a byte list made of the serialized `event_<Name>` string followed by the serialized fields of
+//! the event. Names and types of those fields are described by the schema stored under
+//! `__events_schema`.
+use alloc::{
+    boxed::Box,
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::{self, ToBytes},
+    contracts::NamedKeys,
+    CLType, CLTyped, Key, URef, U256,
+};
+
+use crate::{
+    constants::{
+        CES_EVENTS_KEY_NAME, CES_EVENTS_LENGTH_KEY_NAME, CES_EVENTS_SCHEMA_KEY_NAME,
+        CES_VERSION_KEY_NAME,
+    },
+    detail, Address, Event,
+};
+
+/// Version of the Casper Event Standard implemented by this module.
+const CES_VERSION: &str = "0.1.0";
+
+/// Prefix of the event name serialized in front of each event.
+const EVENT_PREFIX: &str = "event_";
+
+/// Names and types of the fields of a single event, in the order they are serialized.
+struct Schema(Vec<(String, CLType)>);
+
+impl Schema {
+    fn new(fields: &[(&str, CLType)]) -> Self {
+        let fields = fields
+            .iter()
+            .map(|(name, cl_type)| (name.to_string(), cl_type.clone()))
+            .collect();
+        Schema(fields)
+    }
+}
+
+impl CLTyped for Schema {
+    fn cl_type() -> CLType {
+        CLType::List(Box::new(CLType::Tuple2([
+            Box::new(CLType::String),
+            Box::new(CLType::Any),
+        ])))
+    }
+}
+
+impl ToBytes for Schema {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        self.0.to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.0.serialized_length()
+    }
+}
+
+/// Returns the name under which an event is described in the schema.
+fn event_name(event: &Event) -> &'static str {
+    match event {
+        Event::Transfer { .. } => "Transfer",
+        Event::Approval { .. } => "Approval",
+        Event::Mint { .. } => "Mint",
+        Event::Burn { .. } => "Burn",
+        Event::Rebase { .. } => "Rebase",
+    }
+}
+
+/// Returns schemas of all the events recorded by the contract.
+fn schemas() -> BTreeMap<String, Schema> {
+    let mut schemas = BTreeMap::new();
+    schemas.insert(
+        "Transfer".to_string(),
+        Schema::new(&[
+            ("sender", Address::cl_type()),
+            ("recipient", Address::cl_type()),
+            ("amount", U256::cl_type()),
+        ]),
+    );
+    schemas.insert(
+        "Approval".to_string(),
+        Schema::new(&[
+            ("owner", Address::cl_type()),
+            ("spender", Address::cl_type()),
+            ("amount", U256::cl_type()),
+        ]),
+    );
+    schemas.insert(
+        "Mint".to_string(),
+        Schema::new(&[
+            ("recipient", Address::cl_type()),
+            ("amount", U256::cl_type()),
+        ]),
+    );
+    schemas.insert(
+        "Burn".to_string(),
+        Schema::new(&[("owner", Address::cl_type()), ("amount", U256::cl_type())]),
+    );
+    schemas.insert(
+        "Rebase".to_string(),
+        Schema::new(&[
+            ("previous_total_supply", U256::cl_type()),
+            ("new_total_supply", U256::cl_type()),
+        ]),
+    );
+    schemas
+}
+
+/// Serializes an event according to its schema.
+fn event_to_bytes(event: &Event) -> Result<Vec<u8>, bytesrepr::Error> {
+    let mut name = String::from(EVENT_PREFIX);
+    name.push_str(event_name(event));
+
+    let mut result = name.to_bytes()?;
+    match event {
+        Event::Transfer {
+            sender,
+            recipient,
+            amount,
+        } => {
+            result.append(&mut sender.to_bytes()?);
+            result.append(&mut recipient.to_bytes()?);
+            result.append(&mut amount.to_bytes()?);
+        }
+        Event::Approval {
+            owner,
+            spender,
+            amount,
+        } => {
+            result.append(&mut owner.to_bytes()?);
+            result.append(&mut spender.to_bytes()?);
+            result.append(&mut amount.to_bytes()?);
+        }
+        Event::Mint { recipient, amount } => {
+            result.append(&mut recipient.to_bytes()?);
+            result.append(&mut amount.to_bytes()?);
+        }
+        Event::Burn { owner, amount } => {
+            result.append(&mut owner.to_bytes()?);
+            result.append(&mut amount.to_bytes()?);
+        }
+        Event::Rebase {
+            previous_total_supply,
+            new_total_supply,
+        } => {
+            result.append(&mut previous_total_supply.to_bytes()?);
+            result.append(&mut new_total_supply.to_bytes()?);
+        }
+    }
+    Ok(result)
+}
+
+/// Writes an event under the next free index of the CES events dictionary.
+pub(crate) fn write_event_to(events_uref: URef, events_length_uref: URef, event: Event) {
+    let events_length: u32 = storage::read(events_length_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    let event_bytes = event_to_bytes(&event).unwrap_or_revert();
+    storage::dictionary_put(events_uref, &events_length.to_string(), event_bytes);
+    storage::write(events_length_uref, events_length + 1);
+}
+
+/// Gets the [`URef`]s of the CES events dictionary and of the number of events recorded in it.
+pub(crate) fn get_events_urefs() -> (URef, URef) {
+    (
+        detail::get_uref(CES_EVENTS_KEY_NAME),
+        detail::get_uref(CES_EVENTS_LENGTH_KEY_NAME),
+    )
+}
+
+/// Registers the CES schema and sets up the storage of CES events, recording `initial_event` as
+/// the first one.
+///
+/// Returns the [`URef`]s of the events dictionary and of the number of events recorded in it.
+pub(crate) fn install(initial_event: Event, named_keys: &mut NamedKeys) -> (URef, URef) {
+    let events_uref = storage::new_dictionary(CES_EVENTS_KEY_NAME).unwrap_or_revert();
+    let events_length_uref = storage::new_uref(0u32).into_read_write();
+    let schema_uref = storage::new_uref(schemas()).into_read();
+    let version_uref = storage::new_uref(String::from(CES_VERSION)).into_read();
+
+    write_event_to(events_uref, events_length_uref, initial_event);
+
+    runtime::remove_key(CES_EVENTS_KEY_NAME);
+
+    named_keys.insert(CES_EVENTS_KEY_NAME.to_string(), Key::from(events_uref));
+    named_keys.insert(
+        CES_EVENTS_LENGTH_KEY_NAME.to_string(),
+        Key::from(events_length_uref),
+    );
+    named_keys.insert(
+        CES_EVENTS_SCHEMA_KEY_NAME.to_string(),
+        Key::from(schema_uref),
+    );
+    named_keys.insert(CES_VERSION_KEY_NAME.to_string(), Key::from(version_uref));
+
+    (events_uref, events_length_uref)
+}
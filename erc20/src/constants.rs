@@ -12,8 +12,116 @@ pub const ERC20_TOKEN_CONTRACT_KEY_NAME: &str = "erc20_token_contract";
 pub const BALANCES_KEY_NAME: &str = "balances";
 /// Name of dictionary-key for `allowances`
 pub const ALLOWANCES_KEY_NAME: &str = "allowances";
+/// Name of dictionary-key for `nonces`
+pub const NONCES_KEY_NAME: &str = "nonces";
+/// Name of dictionary-key for `roles`
+pub const ROLES_KEY_NAME: &str = "roles";
+/// Name of dictionary-key for `frozen`
+pub const FROZEN_KEY_NAME: &str = "frozen";
+/// Name of dictionary-key for `minter_allowances`
+pub const MINTER_ALLOWANCES_KEY_NAME: &str = "minter_allowances";
+/// Name of named-key for `paused`
+pub const PAUSED_KEY_NAME: &str = "paused";
 /// Name of named-key for `total_supply`
 pub const TOTAL_SUPPLY_KEY_NAME: &str = "total_supply";
+/// Name of named-key for `cap`
+pub const CAP_KEY_NAME: &str = "cap";
+/// Name of named-key for `snapshot_id`
+pub const SNAPSHOT_ID_KEY_NAME: &str = "snapshot_id";
+/// Name of dictionary-key for `balance_snapshots`
+pub const BALANCE_SNAPSHOTS_KEY_NAME: &str = "balance_snapshots";
+/// Name of named-key for `total_supply_snapshots`
+pub const TOTAL_SUPPLY_SNAPSHOTS_KEY_NAME: &str = "total_supply_snapshots";
+/// Name of dictionary-key for `delegates`
+pub const DELEGATES_KEY_NAME: &str = "delegates";
+/// Name of dictionary-key for `votes`
+pub const VOTES_KEY_NAME: &str = "votes";
+/// Name of named-key for `storage_version`
+pub const STORAGE_VERSION_KEY_NAME: &str = "storage_version";
+/// Name of named-key for `flash_fee_basis_points`
+pub const FLASH_FEE_BASIS_POINTS_KEY_NAME: &str = "flash_fee_basis_points";
+/// Name of named-key for `wrapped_purse`
+pub const WRAPPED_PURSE_KEY_NAME: &str = "wrapped_purse";
+/// Name of named-key for `transfer_fee`
+pub const TRANSFER_FEE_KEY_NAME: &str = "transfer_fee";
+/// Name of named-key for `treasury`
+pub const TREASURY_KEY_NAME: &str = "treasury";
+/// Name of dictionary-key for `fee_exempt`
+pub const FEE_EXEMPT_KEY_NAME: &str = "fee_exempt";
+/// Name of named-key for `total_shares`
+pub const TOTAL_SHARES_KEY_NAME: &str = "total_shares";
+/// Name of named-key for `holder_count`
+pub const HOLDER_COUNT_KEY_NAME: &str = "holder_count";
+/// Name of dictionary-key for `holders`
+pub const HOLDERS_KEY_NAME: &str = "holders";
+/// Name of dictionary-key for `holder_indices`
+pub const HOLDER_INDICES_KEY_NAME: &str = "holder_indices";
+/// Name of named-key for `metadata`
+pub const METADATA_KEY_NAME: &str = "metadata";
+/// Name of named-key for `metadata_version`
+pub const METADATA_VERSION_KEY_NAME: &str = "metadata_version";
+/// Name of named-key for `owner`
+pub const OWNER_KEY_NAME: &str = "owner";
+/// Name of named-key for `pending_owner`
+pub const PENDING_OWNER_KEY_NAME: &str = "pending_owner";
+/// Name of dictionary-key for `bridge_transfers`
+pub const BRIDGE_TRANSFERS_KEY_NAME: &str = "bridge_transfers";
+/// Name of dictionary-key for `bridge_requests`
+pub const BRIDGE_REQUESTS_KEY_NAME: &str = "bridge_requests";
+/// Name of named-key for `bridge_request_count`
+pub const BRIDGE_REQUEST_COUNT_KEY_NAME: &str = "bridge_request_count";
+/// Name of dictionary-key for `bridge_limits`
+pub const BRIDGE_LIMITS_KEY_NAME: &str = "bridge_limits";
+/// Name of named-key for `events_mode`
+pub const EVENTS_MODE_KEY_NAME: &str = "events_mode";
+/// Name of dictionary-key for `events`
+pub const EVENTS_KEY_NAME: &str = "events";
+/// Name of named-key for `events_count`
+pub const EVENTS_COUNT_KEY_NAME: &str = "events_count";
+/// Name of dictionary-key for CES `__events`
+pub const CES_EVENTS_KEY_NAME: &str = "__events";
+/// Name of named-key for CES `__events_length`
+pub const CES_EVENTS_LENGTH_KEY_NAME: &str = "__events_length";
+/// Name of named-key for CES `__events_schema`
+pub const CES_EVENTS_SCHEMA_KEY_NAME: &str = "__events_schema";
+/// Name of named-key for CES `__events_ces_version`
+pub const CES_VERSION_KEY_NAME: &str = "__events_ces_version";
+
+/// Name of the role which can grant and revoke roles, and which guards every other administrative
+/// entry point. It follows the ownership of the contract.
+pub const ADMIN_ROLE: &str = "admin";
+/// Name of the role which can mint tokens.
+pub const MINTER_ROLE: &str = "minter";
+/// Name of the role which can burn tokens.
+pub const BURNER_ROLE: &str = "burner";
+/// Name of the role which can mint tokens for transfers bridged from other chains.
+pub const BRIDGE_OPERATOR_ROLE: &str = "bridge_operator";
+
+/// Metadata entry holding the URL of the token's icon.
+pub const METADATA_ICON_URL: &str = "icon_url";
+/// Metadata entry holding the URL of the token's website.
+pub const METADATA_WEBSITE_URL: &str = "website_url";
+/// Metadata entry holding the description of the token.
+pub const METADATA_DESCRIPTION: &str = "description";
+
+/// Version of the layout of the named keys and dictionaries of the contract.
+///
+/// It is bumped whenever the layout changes in a way which the previous versions of the library
+/// can not read:
+///
+/// - 1: initial layout.
+/// - 2: adds the `owner` and `pending_owner` named keys, and the `minter_allowances` dictionary
+///   read by the guarded `mint` entry point.
+pub const STORAGE_VERSION: u32 = 2;
+
+/// Decimals of a wrapped CSPR token, which are the same as the decimals of motes.
+pub const WRAPPED_DECIMALS: u8 = 9;
+
+/// Denominator of fees expressed in basis points.
+pub const BASIS_POINTS: u16 = 10_000;
+
+/// Highest transfer fee which can ever be charged, in basis points.
+pub const MAX_TRANSFER_FEE_BASIS_POINTS: u16 = 1_000;
 
 /// Name of `name` entry point.
 pub const NAME_ENTRY_POINT_NAME: &str = "name";
@@ -33,6 +141,124 @@ pub const ALLOWANCE_ENTRY_POINT_NAME: &str = "allowance";
 pub const TRANSFER_FROM_ENTRY_POINT_NAME: &str = "transfer_from";
 /// Name of `total_supply` entry point.
 pub const TOTAL_SUPPLY_ENTRY_POINT_NAME: &str = "total_supply";
+/// Name of `cap` entry point.
+pub const CAP_ENTRY_POINT_NAME: &str = "cap";
+/// Name of `increase_allowance` entry point.
+pub const INCREASE_ALLOWANCE_ENTRY_POINT_NAME: &str = "increase_allowance";
+/// Name of `decrease_allowance` entry point.
+pub const DECREASE_ALLOWANCE_ENTRY_POINT_NAME: &str = "decrease_allowance";
+/// Name of `permit` entry point.
+pub const PERMIT_ENTRY_POINT_NAME: &str = "permit";
+/// Name of `nonces` entry point.
+pub const NONCES_ENTRY_POINT_NAME: &str = "nonces";
+/// Name of `domain_separator` entry point.
+pub const DOMAIN_SEPARATOR_ENTRY_POINT_NAME: &str = "domain_separator";
+/// Name of `has_role` entry point.
+pub const HAS_ROLE_ENTRY_POINT_NAME: &str = "has_role";
+/// Name of `grant_role` entry point.
+pub const GRANT_ROLE_ENTRY_POINT_NAME: &str = "grant_role";
+/// Name of `revoke_role` entry point.
+pub const REVOKE_ROLE_ENTRY_POINT_NAME: &str = "revoke_role";
+/// Name of `mint` entry point.
+pub const MINT_ENTRY_POINT_NAME: &str = "mint";
+/// Name of `burn` entry point.
+pub const BURN_ENTRY_POINT_NAME: &str = "burn";
+/// Name of `pause` entry point.
+pub const PAUSE_ENTRY_POINT_NAME: &str = "pause";
+/// Name of `unpause` entry point.
+pub const UNPAUSE_ENTRY_POINT_NAME: &str = "unpause";
+/// Name of `paused` entry point.
+pub const PAUSED_ENTRY_POINT_NAME: &str = "paused";
+/// Name of `freeze` entry point.
+pub const FREEZE_ENTRY_POINT_NAME: &str = "freeze";
+/// Name of `unfreeze` entry point.
+pub const UNFREEZE_ENTRY_POINT_NAME: &str = "unfreeze";
+/// Name of `is_frozen` entry point.
+pub const IS_FROZEN_ENTRY_POINT_NAME: &str = "is_frozen";
+/// Name of `snapshot` entry point.
+pub const SNAPSHOT_ENTRY_POINT_NAME: &str = "snapshot";
+/// Name of `balance_of_at` entry point.
+pub const BALANCE_OF_AT_ENTRY_POINT_NAME: &str = "balance_of_at";
+/// Name of `total_supply_at` entry point.
+pub const TOTAL_SUPPLY_AT_ENTRY_POINT_NAME: &str = "total_supply_at";
+/// Name of `delegate` entry point.
+pub const DELEGATE_ENTRY_POINT_NAME: &str = "delegate";
+/// Name of `delegates` entry point.
+pub const DELEGATES_ENTRY_POINT_NAME: &str = "delegates";
+/// Name of `get_votes` entry point.
+pub const GET_VOTES_ENTRY_POINT_NAME: &str = "get_votes";
+/// Name of `get_past_votes` entry point.
+pub const GET_PAST_VOTES_ENTRY_POINT_NAME: &str = "get_past_votes";
+/// Name of `storage_version` entry point.
+pub const STORAGE_VERSION_ENTRY_POINT_NAME: &str = "storage_version";
+/// Name of `batch_transfer` entry point.
+pub const BATCH_TRANSFER_ENTRY_POINT_NAME: &str = "batch_transfer";
+/// Name of `transfer_and_call` entry point.
+pub const TRANSFER_AND_CALL_ENTRY_POINT_NAME: &str = "transfer_and_call";
+/// Name of `approve_and_call` entry point.
+pub const APPROVE_AND_CALL_ENTRY_POINT_NAME: &str = "approve_and_call";
+/// Name of `on_erc20_received` entry point of a token receiver.
+pub const ON_ERC20_RECEIVED_ENTRY_POINT_NAME: &str = "on_erc20_received";
+/// Name of `on_erc20_approved` entry point of a token receiver.
+pub const ON_ERC20_APPROVED_ENTRY_POINT_NAME: &str = "on_erc20_approved";
+/// Name of `max_flash_loan` entry point.
+pub const MAX_FLASH_LOAN_ENTRY_POINT_NAME: &str = "max_flash_loan";
+/// Name of `flash_fee` entry point.
+pub const FLASH_FEE_ENTRY_POINT_NAME: &str = "flash_fee";
+/// Name of `flash_loan` entry point.
+pub const FLASH_LOAN_ENTRY_POINT_NAME: &str = "flash_loan";
+/// Name of `on_flash_loan` entry point of a flash loan borrower.
+pub const ON_FLASH_LOAN_ENTRY_POINT_NAME: &str = "on_flash_loan";
+/// Name of `deposit` entry point.
+pub const DEPOSIT_ENTRY_POINT_NAME: &str = "deposit";
+/// Name of `withdraw` entry point.
+pub const WITHDRAW_ENTRY_POINT_NAME: &str = "withdraw";
+/// Name of `set_transfer_fee` entry point.
+pub const SET_TRANSFER_FEE_ENTRY_POINT_NAME: &str = "set_transfer_fee";
+/// Name of `set_fee_exempt` entry point.
+pub const SET_FEE_EXEMPT_ENTRY_POINT_NAME: &str = "set_fee_exempt";
+/// Name of `rebase` entry point.
+pub const REBASE_ENTRY_POINT_NAME: &str = "rebase";
+/// Name of `holder_count` entry point.
+pub const HOLDER_COUNT_ENTRY_POINT_NAME: &str = "holder_count";
+/// Name of `holders` entry point.
+pub const HOLDERS_ENTRY_POINT_NAME: &str = "holders";
+/// Name of `approve_with_expiry` entry point.
+pub const APPROVE_WITH_EXPIRY_ENTRY_POINT_NAME: &str = "approve_with_expiry";
+/// Name of `token_metadata` entry point.
+pub const TOKEN_METADATA_ENTRY_POINT_NAME: &str = "token_metadata";
+/// Name of `metadata_version` entry point.
+pub const METADATA_VERSION_ENTRY_POINT_NAME: &str = "metadata_version";
+/// Name of `set_metadata` entry point.
+pub const SET_METADATA_ENTRY_POINT_NAME: &str = "set_metadata";
+/// Name of `owner` entry point.
+pub const OWNER_ENTRY_POINT_NAME: &str = "owner";
+/// Name of `pending_owner` entry point.
+pub const PENDING_OWNER_ENTRY_POINT_NAME: &str = "pending_owner";
+/// Name of `transfer_ownership` entry point.
+pub const TRANSFER_OWNERSHIP_ENTRY_POINT_NAME: &str = "transfer_ownership";
+/// Name of `accept_ownership` entry point.
+pub const ACCEPT_OWNERSHIP_ENTRY_POINT_NAME: &str = "accept_ownership";
+/// Name of `renounce_ownership` entry point.
+pub const RENOUNCE_OWNERSHIP_ENTRY_POINT_NAME: &str = "renounce_ownership";
+/// Name of `configure_minter` entry point.
+pub const CONFIGURE_MINTER_ENTRY_POINT_NAME: &str = "configure_minter";
+/// Name of `remove_minter` entry point.
+pub const REMOVE_MINTER_ENTRY_POINT_NAME: &str = "remove_minter";
+/// Name of `minter_allowance` entry point.
+pub const MINTER_ALLOWANCE_ENTRY_POINT_NAME: &str = "minter_allowance";
+/// Name of `mint_bridged` entry point.
+pub const MINT_BRIDGED_ENTRY_POINT_NAME: &str = "mint_bridged";
+/// Name of `bridge_transfer` entry point.
+pub const BRIDGE_TRANSFER_ENTRY_POINT_NAME: &str = "bridge_transfer";
+/// Name of `request_bridge_back` entry point.
+pub const REQUEST_BRIDGE_BACK_ENTRY_POINT_NAME: &str = "request_bridge_back";
+/// Name of `bridge_request` entry point.
+pub const BRIDGE_REQUEST_ENTRY_POINT_NAME: &str = "bridge_request";
+/// Name of `bridge_request_count` entry point.
+pub const BRIDGE_REQUEST_COUNT_ENTRY_POINT_NAME: &str = "bridge_request_count";
+/// Name of `set_bridge_limits` entry point.
+pub const SET_BRIDGE_LIMITS_ENTRY_POINT_NAME: &str = "set_bridge_limits";
 
 /// Name of `address` runtime argument.
 pub const ADDRESS_RUNTIME_ARG_NAME: &str = "address";
@@ -52,3 +278,71 @@ pub const SYMBOL_RUNTIME_ARG_NAME: &str = "symbol";
 pub const DECIMALS_RUNTIME_ARG_NAME: &str = "decimals";
 /// Name of `total_supply` runtime argument.
 pub const TOTAL_SUPPLY_RUNTIME_ARG_NAME: &str = "total_supply";
+/// Name of `events_mode` runtime argument.
+pub const EVENTS_MODE_RUNTIME_ARG_NAME: &str = "events_mode";
+/// Name of `deadline` runtime argument.
+pub const DEADLINE_RUNTIME_ARG_NAME: &str = "deadline";
+/// Name of `nonce` runtime argument.
+pub const NONCE_RUNTIME_ARG_NAME: &str = "nonce";
+/// Name of `signature` runtime argument.
+pub const SIGNATURE_RUNTIME_ARG_NAME: &str = "signature";
+/// Name of `role` runtime argument.
+pub const ROLE_RUNTIME_ARG_NAME: &str = "role";
+/// Name of `snapshot_id` runtime argument.
+pub const SNAPSHOT_ID_RUNTIME_ARG_NAME: &str = "snapshot_id";
+/// Name of `delegatee` runtime argument.
+pub const DELEGATEE_RUNTIME_ARG_NAME: &str = "delegatee";
+/// Name of `account` runtime argument.
+pub const ACCOUNT_RUNTIME_ARG_NAME: &str = "account";
+/// Name of `block_time` runtime argument.
+pub const BLOCK_TIME_RUNTIME_ARG_NAME: &str = "block_time";
+/// Name of `recipients` runtime argument.
+pub const RECIPIENTS_RUNTIME_ARG_NAME: &str = "recipients";
+/// Name of `amounts` runtime argument.
+pub const AMOUNTS_RUNTIME_ARG_NAME: &str = "amounts";
+/// Name of `sender` runtime argument.
+pub const SENDER_RUNTIME_ARG_NAME: &str = "sender";
+/// Name of `data` runtime argument.
+pub const DATA_RUNTIME_ARG_NAME: &str = "data";
+/// Name of `receiver` runtime argument.
+pub const RECEIVER_RUNTIME_ARG_NAME: &str = "receiver";
+/// Name of `initiator` runtime argument.
+pub const INITIATOR_RUNTIME_ARG_NAME: &str = "initiator";
+/// Name of `fee` runtime argument.
+pub const FEE_RUNTIME_ARG_NAME: &str = "fee";
+/// Name of `purse` runtime argument.
+pub const PURSE_RUNTIME_ARG_NAME: &str = "purse";
+/// Name of `basis_points` runtime argument.
+pub const BASIS_POINTS_RUNTIME_ARG_NAME: &str = "basis_points";
+/// Name of `minimum` runtime argument.
+pub const MINIMUM_RUNTIME_ARG_NAME: &str = "minimum";
+/// Name of `exempt` runtime argument.
+pub const EXEMPT_RUNTIME_ARG_NAME: &str = "exempt";
+/// Name of `offset` runtime argument.
+pub const OFFSET_RUNTIME_ARG_NAME: &str = "offset";
+/// Name of `limit` runtime argument.
+pub const LIMIT_RUNTIME_ARG_NAME: &str = "limit";
+/// Name of `expires_at` runtime argument.
+pub const EXPIRES_AT_RUNTIME_ARG_NAME: &str = "expires_at";
+/// Name of `key` runtime argument.
+pub const KEY_RUNTIME_ARG_NAME: &str = "key";
+/// Name of `value` runtime argument.
+pub const VALUE_RUNTIME_ARG_NAME: &str = "value";
+/// Name of `new_owner` runtime argument.
+pub const NEW_OWNER_RUNTIME_ARG_NAME: &str = "new_owner";
+/// Name of `minter` runtime argument.
+pub const MINTER_RUNTIME_ARG_NAME: &str = "minter";
+/// Name of `allowance` runtime argument.
+pub const ALLOWANCE_RUNTIME_ARG_NAME: &str = "allowance";
+/// Name of `source_chain_id` runtime argument.
+pub const SOURCE_CHAIN_ID_RUNTIME_ARG_NAME: &str = "source_chain_id";
+/// Name of `source_tx_hash` runtime argument.
+pub const SOURCE_TX_HASH_RUNTIME_ARG_NAME: &str = "source_tx_hash";
+/// Name of `destination_chain_id` runtime argument.
+pub const DESTINATION_CHAIN_ID_RUNTIME_ARG_NAME: &str = "destination_chain_id";
+/// Name of `destination_address` runtime argument.
+pub const DESTINATION_ADDRESS_RUNTIME_ARG_NAME: &str = "destination_address";
+/// Name of `maximum` runtime argument.
+pub const MAXIMUM_RUNTIME_ARG_NAME: &str = "maximum";
+/// Name of `index` runtime argument.
+pub const INDEX_RUNTIME_ARG_NAME: &str = "index";
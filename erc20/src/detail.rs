@@ -1,11 +1,16 @@
 //! Implementation details.
+use alloc::string::String;
 use core::convert::TryInto;
 
 use casper_contract::{
     contract_api::{runtime, storage},
     unwrap_or_revert::UnwrapOrRevert,
 };
-use casper_types::{bytesrepr::FromBytes, system::CallStackElement, ApiError, CLTyped, URef};
+use casper_types::{
+    bytesrepr::{FromBytes, ToBytes},
+    system::CallStackElement,
+    ApiError, CLTyped, ContractHash, ContractPackageHash, URef, U256, U512,
+};
 
 use crate::{error::Error, Address};
 
@@ -17,6 +22,35 @@ pub(crate) fn get_uref(name: &str) -> URef {
     key.try_into().unwrap_or_revert()
 }
 
+/// Gets [`URef`] under a name, if the name is present in the named keys.
+///
+/// This is used to detect optional features which were not enabled at install time.
+pub(crate) fn get_optional_uref(name: &str) -> Option<URef> {
+    runtime::get_key(name).map(|key| key.try_into().unwrap_or_revert())
+}
+
+/// Gets [`ContractPackageHash`] under a name.
+pub(crate) fn get_contract_package_hash(name: &str) -> ContractPackageHash {
+    let key = runtime::get_key(name)
+        .ok_or(ApiError::MissingKey)
+        .unwrap_or_revert();
+    key.into_hash()
+        .map(ContractPackageHash::new)
+        .ok_or(ApiError::UnexpectedKeyVariant)
+        .unwrap_or_revert()
+}
+
+/// Gets [`ContractHash`] under a name.
+pub(crate) fn get_contract_hash(name: &str) -> ContractHash {
+    let key = runtime::get_key(name)
+        .ok_or(ApiError::MissingKey)
+        .unwrap_or_revert();
+    key.into_hash()
+        .map(ContractHash::new)
+        .ok_or(ApiError::UnexpectedKeyVariant)
+        .unwrap_or_revert()
+}
+
 /// Reads value from a named key.
 pub(crate) fn read_from<T>(name: &str) -> T
 where
@@ -27,6 +61,36 @@ where
     value
 }
 
+/// Widens a [`U256`] into a [`U512`].
+pub(crate) fn u256_to_u512(value: U256) -> U512 {
+    let mut bytes = [0u8; 32];
+    value.to_little_endian(&mut bytes);
+    U512::from_little_endian(&bytes)
+}
+
+/// Narrows a [`U512`] into a [`U256`], failing if it does not fit.
+pub(crate) fn u512_to_u256(value: U512) -> Result<U256, Error> {
+    let mut bytes = [0u8; 64];
+    value.to_little_endian(&mut bytes);
+    if bytes[32..].iter().any(|byte| *byte != 0) {
+        return Err(Error::Overflow);
+    }
+    Ok(U256::from_little_endian(&bytes[..32]))
+}
+
+/// Creates a dictionary item key for a dictionary keyed by an [`Address`].
+#[inline]
+pub(crate) fn make_dictionary_item_key(owner: Address) -> String {
+    let preimage = owner.to_bytes().unwrap_or_revert();
+    // NOTE: As for now dictionary item keys are limited to 64 characters only. Instead of using
+    // hashing (which will effectively hash a hash) we'll use base64. Preimage is about 33 bytes for
+    // both Address variants, and approximated base64-encoded length will be 4 * (33 / 3) ~ 44
+    // characters.
+    // Even if the preimage increased in size we still have extra space but even in case of much
+    // larger preimage we can switch to base85 which has ratio of 4:5.
+    base64::encode(&preimage)
+}
+
 /// Gets the immediate call stack element of the current execution.
 fn get_immediate_call_stack_item() -> Option<CallStackElement> {
     let call_stack = runtime::get_call_stack();
@@ -62,6 +126,20 @@ pub(crate) fn get_immediate_caller_address() -> Result<Address, Error> {
         .ok_or(Error::InvalidContext)
 }
 
+/// Gets the package hash of the currently executing contract.
+///
+/// Returns [`Error::InvalidContext`] when called outside of a stored contract.
+pub(crate) fn get_current_contract_package_hash() -> Result<ContractPackageHash, Error> {
+    let call_stack = runtime::get_call_stack();
+    match call_stack.into_iter().rev().next() {
+        Some(CallStackElement::StoredContract {
+            contract_package_hash,
+            ..
+        }) => Ok(contract_package_hash),
+        _ => Err(Error::InvalidContext),
+    }
+}
+
 /// Gets the caller address which is stored on the top of the call stack.
 ///
 /// This is similar to what [`runtime::get_caller`] does but it also supports stored contracts.
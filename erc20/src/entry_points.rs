@@ -1,18 +1,54 @@
 //! Contains definition of the entry points.
-use alloc::{string::String, vec, vec::Vec};
+use alloc::{collections::BTreeMap, string::String, vec, vec::Vec};
 
 use casper_types::{
-    CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Parameter, U256,
+    bytesrepr::Bytes, CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints,
+    Parameter, PublicKey, URef, U256, U512,
 };
 
 use crate::{
     address::Address,
+    bridge::{BridgeRequest, BridgeTransfer},
     constants::{
-        ADDRESS_RUNTIME_ARG_NAME, ALLOWANCE_ENTRY_POINT_NAME, AMOUNT_RUNTIME_ARG_NAME,
-        APPROVE_ENTRY_POINT_NAME, BALANCE_OF_ENTRY_POINT_NAME, DECIMALS_ENTRY_POINT_NAME,
-        NAME_ENTRY_POINT_NAME, OWNER_RUNTIME_ARG_NAME, RECIPIENT_RUNTIME_ARG_NAME,
-        SPENDER_RUNTIME_ARG_NAME, SYMBOL_ENTRY_POINT_NAME, TOTAL_SUPPLY_ENTRY_POINT_NAME,
-        TRANSFER_ENTRY_POINT_NAME, TRANSFER_FROM_ENTRY_POINT_NAME,
+        ACCEPT_OWNERSHIP_ENTRY_POINT_NAME, ACCOUNT_RUNTIME_ARG_NAME, ADDRESS_RUNTIME_ARG_NAME,
+        ALLOWANCE_ENTRY_POINT_NAME, ALLOWANCE_RUNTIME_ARG_NAME, AMOUNTS_RUNTIME_ARG_NAME,
+        AMOUNT_RUNTIME_ARG_NAME, APPROVE_AND_CALL_ENTRY_POINT_NAME, APPROVE_ENTRY_POINT_NAME,
+        APPROVE_WITH_EXPIRY_ENTRY_POINT_NAME, BALANCE_OF_AT_ENTRY_POINT_NAME,
+        BALANCE_OF_ENTRY_POINT_NAME, BASIS_POINTS_RUNTIME_ARG_NAME,
+        BATCH_TRANSFER_ENTRY_POINT_NAME, BLOCK_TIME_RUNTIME_ARG_NAME,
+        BRIDGE_REQUEST_COUNT_ENTRY_POINT_NAME, BRIDGE_REQUEST_ENTRY_POINT_NAME,
+        BRIDGE_TRANSFER_ENTRY_POINT_NAME, BURN_ENTRY_POINT_NAME, CAP_ENTRY_POINT_NAME,
+        CONFIGURE_MINTER_ENTRY_POINT_NAME, DATA_RUNTIME_ARG_NAME, DEADLINE_RUNTIME_ARG_NAME,
+        DECIMALS_ENTRY_POINT_NAME, DECREASE_ALLOWANCE_ENTRY_POINT_NAME, DELEGATEE_RUNTIME_ARG_NAME,
+        DELEGATES_ENTRY_POINT_NAME, DELEGATE_ENTRY_POINT_NAME, DEPOSIT_ENTRY_POINT_NAME,
+        DESTINATION_ADDRESS_RUNTIME_ARG_NAME, DESTINATION_CHAIN_ID_RUNTIME_ARG_NAME,
+        DOMAIN_SEPARATOR_ENTRY_POINT_NAME, EXEMPT_RUNTIME_ARG_NAME, EXPIRES_AT_RUNTIME_ARG_NAME,
+        FLASH_FEE_ENTRY_POINT_NAME, FLASH_LOAN_ENTRY_POINT_NAME, FREEZE_ENTRY_POINT_NAME,
+        GET_PAST_VOTES_ENTRY_POINT_NAME, GET_VOTES_ENTRY_POINT_NAME, GRANT_ROLE_ENTRY_POINT_NAME,
+        HAS_ROLE_ENTRY_POINT_NAME, HOLDERS_ENTRY_POINT_NAME, HOLDER_COUNT_ENTRY_POINT_NAME,
+        INCREASE_ALLOWANCE_ENTRY_POINT_NAME, INDEX_RUNTIME_ARG_NAME, IS_FROZEN_ENTRY_POINT_NAME,
+        KEY_RUNTIME_ARG_NAME, LIMIT_RUNTIME_ARG_NAME, MAXIMUM_RUNTIME_ARG_NAME,
+        MAX_FLASH_LOAN_ENTRY_POINT_NAME, METADATA_VERSION_ENTRY_POINT_NAME,
+        MINIMUM_RUNTIME_ARG_NAME, MINTER_ALLOWANCE_ENTRY_POINT_NAME, MINTER_RUNTIME_ARG_NAME,
+        MINT_BRIDGED_ENTRY_POINT_NAME, MINT_ENTRY_POINT_NAME, NAME_ENTRY_POINT_NAME,
+        NEW_OWNER_RUNTIME_ARG_NAME, NONCES_ENTRY_POINT_NAME, NONCE_RUNTIME_ARG_NAME,
+        OFFSET_RUNTIME_ARG_NAME, OWNER_ENTRY_POINT_NAME, OWNER_RUNTIME_ARG_NAME,
+        PAUSED_ENTRY_POINT_NAME, PAUSE_ENTRY_POINT_NAME, PENDING_OWNER_ENTRY_POINT_NAME,
+        PERMIT_ENTRY_POINT_NAME, PURSE_RUNTIME_ARG_NAME, REBASE_ENTRY_POINT_NAME,
+        RECEIVER_RUNTIME_ARG_NAME, RECIPIENTS_RUNTIME_ARG_NAME, RECIPIENT_RUNTIME_ARG_NAME,
+        REMOVE_MINTER_ENTRY_POINT_NAME, RENOUNCE_OWNERSHIP_ENTRY_POINT_NAME,
+        REQUEST_BRIDGE_BACK_ENTRY_POINT_NAME, REVOKE_ROLE_ENTRY_POINT_NAME, ROLE_RUNTIME_ARG_NAME,
+        SET_BRIDGE_LIMITS_ENTRY_POINT_NAME, SET_FEE_EXEMPT_ENTRY_POINT_NAME,
+        SET_METADATA_ENTRY_POINT_NAME, SET_TRANSFER_FEE_ENTRY_POINT_NAME,
+        SIGNATURE_RUNTIME_ARG_NAME, SNAPSHOT_ENTRY_POINT_NAME, SNAPSHOT_ID_RUNTIME_ARG_NAME,
+        SOURCE_CHAIN_ID_RUNTIME_ARG_NAME, SOURCE_TX_HASH_RUNTIME_ARG_NAME,
+        SPENDER_RUNTIME_ARG_NAME, STORAGE_VERSION_ENTRY_POINT_NAME, SYMBOL_ENTRY_POINT_NAME,
+        TOKEN_METADATA_ENTRY_POINT_NAME, TOTAL_SUPPLY_AT_ENTRY_POINT_NAME,
+        TOTAL_SUPPLY_ENTRY_POINT_NAME, TOTAL_SUPPLY_RUNTIME_ARG_NAME,
+        TRANSFER_AND_CALL_ENTRY_POINT_NAME, TRANSFER_ENTRY_POINT_NAME,
+        TRANSFER_FROM_ENTRY_POINT_NAME, TRANSFER_OWNERSHIP_ENTRY_POINT_NAME,
+        UNFREEZE_ENTRY_POINT_NAME, UNPAUSE_ENTRY_POINT_NAME, VALUE_RUNTIME_ARG_NAME,
+        WITHDRAW_ENTRY_POINT_NAME,
     },
 };
 
@@ -80,6 +116,88 @@ pub fn approve() -> EntryPoint {
     )
 }
 
+/// Returns the `increase_allowance` entry point.
+pub fn increase_allowance() -> EntryPoint {
+    EntryPoint::new(
+        String::from(INCREASE_ALLOWANCE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(SPENDER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `decrease_allowance` entry point.
+pub fn decrease_allowance() -> EntryPoint {
+    EntryPoint::new(
+        String::from(DECREASE_ALLOWANCE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(SPENDER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `permit` entry point.
+pub fn permit() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PERMIT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER_RUNTIME_ARG_NAME, PublicKey::cl_type()),
+            Parameter::new(SPENDER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(DEADLINE_RUNTIME_ARG_NAME, u64::cl_type()),
+            Parameter::new(NONCE_RUNTIME_ARG_NAME, u64::cl_type()),
+            Parameter::new(SIGNATURE_RUNTIME_ARG_NAME, Vec::<u8>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `nonces` entry point.
+pub fn nonces() -> EntryPoint {
+    EntryPoint::new(
+        String::from(NONCES_ENTRY_POINT_NAME),
+        vec![Parameter::new(OWNER_RUNTIME_ARG_NAME, Address::cl_type())],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `domain_separator` entry point.
+pub fn domain_separator() -> EntryPoint {
+    EntryPoint::new(
+        String::from(DOMAIN_SEPARATOR_ENTRY_POINT_NAME),
+        Vec::new(),
+        <[u8; 32]>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `storage_version` entry point.
+///
+/// It is required by [`ERC20::upgrade`](crate::ERC20::upgrade) to check the storage layout of the
+/// contract being upgraded.
+pub fn storage_version() -> EntryPoint {
+    EntryPoint::new(
+        String::from(STORAGE_VERSION_ENTRY_POINT_NAME),
+        Vec::new(),
+        u32::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the `transfer` entry point.
 pub fn transfer() -> EntryPoint {
     EntryPoint::new(
@@ -116,6 +234,17 @@ pub fn total_supply() -> EntryPoint {
     )
 }
 
+/// Returns the `cap` entry point.
+pub fn cap() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CAP_ENTRY_POINT_NAME),
+        Vec::new(),
+        Option::<U256>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the `decimals` entry point.
 pub fn decimals() -> EntryPoint {
     EntryPoint::new(
@@ -127,6 +256,634 @@ pub fn decimals() -> EntryPoint {
     )
 }
 
+/// Returns the `has_role` entry point.
+pub fn has_role() -> EntryPoint {
+    EntryPoint::new(
+        String::from(HAS_ROLE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ROLE_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type()),
+        ],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `grant_role` entry point.
+pub fn grant_role() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GRANT_ROLE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ROLE_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `revoke_role` entry point.
+pub fn revoke_role() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REVOKE_ROLE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ROLE_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `mint` entry point.
+///
+/// The entry point is public, hence its implementation has to check the role of the caller, e.g.
+/// by calling [`ERC20::mint_as_minter`](crate::ERC20::mint_as_minter).
+pub fn mint() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MINT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `burn` entry point.
+///
+/// The entry point is public, hence its implementation has to check the role of the caller, e.g.
+/// by calling [`ERC20::burn_as_burner`](crate::ERC20::burn_as_burner).
+pub fn burn() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BURN_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `pause` entry point.
+pub fn pause() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PAUSE_ENTRY_POINT_NAME),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `unpause` entry point.
+pub fn unpause() -> EntryPoint {
+    EntryPoint::new(
+        String::from(UNPAUSE_ENTRY_POINT_NAME),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `paused` entry point.
+pub fn paused() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PAUSED_ENTRY_POINT_NAME),
+        Vec::new(),
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `freeze` entry point.
+pub fn freeze() -> EntryPoint {
+    EntryPoint::new(
+        String::from(FREEZE_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `unfreeze` entry point.
+pub fn unfreeze() -> EntryPoint {
+    EntryPoint::new(
+        String::from(UNFREEZE_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `is_frozen` entry point.
+pub fn is_frozen() -> EntryPoint {
+    EntryPoint::new(
+        String::from(IS_FROZEN_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type())],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `snapshot` entry point.
+pub fn snapshot() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SNAPSHOT_ENTRY_POINT_NAME),
+        Vec::new(),
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `balance_of_at` entry point.
+pub fn balance_of_at() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BALANCE_OF_AT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(SNAPSHOT_ID_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `total_supply_at` entry point.
+pub fn total_supply_at() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TOTAL_SUPPLY_AT_ENTRY_POINT_NAME),
+        vec![Parameter::new(SNAPSHOT_ID_RUNTIME_ARG_NAME, u64::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `delegate` entry point.
+pub fn delegate() -> EntryPoint {
+    EntryPoint::new(
+        String::from(DELEGATE_ENTRY_POINT_NAME),
+        vec![Parameter::new(
+            DELEGATEE_RUNTIME_ARG_NAME,
+            Address::cl_type(),
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `delegates` entry point.
+pub fn delegates() -> EntryPoint {
+    EntryPoint::new(
+        String::from(DELEGATES_ENTRY_POINT_NAME),
+        vec![Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Address::cl_type())],
+        Option::<Address>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `get_votes` entry point.
+pub fn get_votes() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_VOTES_ENTRY_POINT_NAME),
+        vec![Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Address::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `get_past_votes` entry point.
+pub fn get_past_votes() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_PAST_VOTES_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(BLOCK_TIME_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `batch_transfer` entry point.
+pub fn batch_transfer() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BATCH_TRANSFER_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENTS_RUNTIME_ARG_NAME, Vec::<Address>::cl_type()),
+            Parameter::new(AMOUNTS_RUNTIME_ARG_NAME, Vec::<U256>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `transfer_and_call` entry point.
+pub fn transfer_and_call() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TRANSFER_AND_CALL_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENT_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(DATA_RUNTIME_ARG_NAME, Bytes::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `approve_and_call` entry point.
+pub fn approve_and_call() -> EntryPoint {
+    EntryPoint::new(
+        String::from(APPROVE_AND_CALL_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(SPENDER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(DATA_RUNTIME_ARG_NAME, Bytes::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `max_flash_loan` entry point.
+pub fn max_flash_loan() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MAX_FLASH_LOAN_ENTRY_POINT_NAME),
+        Vec::new(),
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `flash_fee` entry point.
+pub fn flash_fee() -> EntryPoint {
+    EntryPoint::new(
+        String::from(FLASH_FEE_ENTRY_POINT_NAME),
+        vec![Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `flash_loan` entry point.
+pub fn flash_loan() -> EntryPoint {
+    EntryPoint::new(
+        String::from(FLASH_LOAN_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECEIVER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(DATA_RUNTIME_ARG_NAME, Bytes::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `deposit` entry point.
+pub fn deposit() -> EntryPoint {
+    EntryPoint::new(
+        String::from(DEPOSIT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(PURSE_RUNTIME_ARG_NAME, URef::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U512::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `withdraw` entry point.
+pub fn withdraw() -> EntryPoint {
+    EntryPoint::new(
+        String::from(WITHDRAW_ENTRY_POINT_NAME),
+        vec![Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_transfer_fee` entry point.
+pub fn set_transfer_fee() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_TRANSFER_FEE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(BASIS_POINTS_RUNTIME_ARG_NAME, u16::cl_type()),
+            Parameter::new(MINIMUM_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_fee_exempt` entry point.
+pub fn set_fee_exempt() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_FEE_EXEMPT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(EXEMPT_RUNTIME_ARG_NAME, bool::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `rebase` entry point.
+pub fn rebase() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REBASE_ENTRY_POINT_NAME),
+        vec![Parameter::new(
+            TOTAL_SUPPLY_RUNTIME_ARG_NAME,
+            U256::cl_type(),
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `holder_count` entry point.
+pub fn holder_count() -> EntryPoint {
+    EntryPoint::new(
+        String::from(HOLDER_COUNT_ENTRY_POINT_NAME),
+        Vec::new(),
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `holders` entry point.
+pub fn holders() -> EntryPoint {
+    EntryPoint::new(
+        String::from(HOLDERS_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OFFSET_RUNTIME_ARG_NAME, u64::cl_type()),
+            Parameter::new(LIMIT_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        Vec::<Address>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `approve_with_expiry` entry point.
+pub fn approve_with_expiry() -> EntryPoint {
+    EntryPoint::new(
+        String::from(APPROVE_WITH_EXPIRY_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(SPENDER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(EXPIRES_AT_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `token_metadata` entry point.
+pub fn token_metadata() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TOKEN_METADATA_ENTRY_POINT_NAME),
+        Vec::new(),
+        BTreeMap::<String, String>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `metadata_version` entry point.
+pub fn metadata_version() -> EntryPoint {
+    EntryPoint::new(
+        String::from(METADATA_VERSION_ENTRY_POINT_NAME),
+        Vec::new(),
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_metadata` entry point.
+pub fn set_metadata() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_METADATA_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(KEY_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(VALUE_RUNTIME_ARG_NAME, String::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `owner` entry point.
+pub fn owner() -> EntryPoint {
+    EntryPoint::new(
+        String::from(OWNER_ENTRY_POINT_NAME),
+        Vec::new(),
+        Option::<Address>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `pending_owner` entry point.
+pub fn pending_owner() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PENDING_OWNER_ENTRY_POINT_NAME),
+        Vec::new(),
+        Option::<Address>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `transfer_ownership` entry point.
+pub fn transfer_ownership() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TRANSFER_OWNERSHIP_ENTRY_POINT_NAME),
+        vec![Parameter::new(
+            NEW_OWNER_RUNTIME_ARG_NAME,
+            Address::cl_type(),
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `accept_ownership` entry point.
+pub fn accept_ownership() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ACCEPT_OWNERSHIP_ENTRY_POINT_NAME),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `renounce_ownership` entry point.
+pub fn renounce_ownership() -> EntryPoint {
+    EntryPoint::new(
+        String::from(RENOUNCE_OWNERSHIP_ENTRY_POINT_NAME),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `configure_minter` entry point.
+pub fn configure_minter() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CONFIGURE_MINTER_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(MINTER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(ALLOWANCE_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `remove_minter` entry point.
+pub fn remove_minter() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REMOVE_MINTER_ENTRY_POINT_NAME),
+        vec![Parameter::new(MINTER_RUNTIME_ARG_NAME, Address::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `minter_allowance` entry point.
+pub fn minter_allowance() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MINTER_ALLOWANCE_ENTRY_POINT_NAME),
+        vec![Parameter::new(MINTER_RUNTIME_ARG_NAME, Address::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `mint_bridged` entry point.
+pub fn mint_bridged() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MINT_BRIDGED_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENT_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(SOURCE_CHAIN_ID_RUNTIME_ARG_NAME, u64::cl_type()),
+            Parameter::new(SOURCE_TX_HASH_RUNTIME_ARG_NAME, Bytes::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `bridge_transfer` entry point.
+pub fn bridge_transfer() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BRIDGE_TRANSFER_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(SOURCE_CHAIN_ID_RUNTIME_ARG_NAME, u64::cl_type()),
+            Parameter::new(SOURCE_TX_HASH_RUNTIME_ARG_NAME, Bytes::cl_type()),
+        ],
+        Option::<BridgeTransfer>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `request_bridge_back` entry point.
+pub fn request_bridge_back() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REQUEST_BRIDGE_BACK_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(DESTINATION_CHAIN_ID_RUNTIME_ARG_NAME, u64::cl_type()),
+            Parameter::new(DESTINATION_ADDRESS_RUNTIME_ARG_NAME, Vec::<u8>::cl_type()),
+        ],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `bridge_request` entry point.
+pub fn bridge_request() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BRIDGE_REQUEST_ENTRY_POINT_NAME),
+        vec![Parameter::new(INDEX_RUNTIME_ARG_NAME, u64::cl_type())],
+        Option::<BridgeRequest>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `bridge_request_count` entry point.
+pub fn bridge_request_count() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BRIDGE_REQUEST_COUNT_ENTRY_POINT_NAME),
+        Vec::new(),
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_bridge_limits` entry point.
+pub fn set_bridge_limits() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_BRIDGE_LIMITS_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(DESTINATION_CHAIN_ID_RUNTIME_ARG_NAME, u64::cl_type()),
+            Parameter::new(MINIMUM_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(MAXIMUM_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the default set of ERC20 token entry points.
 pub fn default() -> EntryPoints {
     let mut entry_points = EntryPoints::new();
@@ -139,5 +896,11 @@ pub fn default() -> EntryPoints {
     entry_points.add_entry_point(approve());
     entry_points.add_entry_point(allowance());
     entry_points.add_entry_point(transfer_from());
+    entry_points.add_entry_point(increase_allowance());
+    entry_points.add_entry_point(decrease_allowance());
+    entry_points.add_entry_point(permit());
+    entry_points.add_entry_point(nonces());
+    entry_points.add_entry_point(domain_separator());
+    entry_points.add_entry_point(storage_version());
     entry_points
 }
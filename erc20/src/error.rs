@@ -9,7 +9,7 @@ use casper_types::ApiError;
 /// return those via the [`Error::User`] variant or equivalently via the [`ApiError::User`]
 /// variant.
 ///
-/// Such a user error should be in the range `[0..(u16::MAX - 4)]` (i.e. [0, 65532]) to avoid
+/// Such a user error should be in the range `[0..(u16::MAX - 28)]` (i.e. [0, 65507]) to avoid
 /// conflicting with the other `Error` variants.
 pub enum Error {
     /// ERC20 contract called from within an invalid context.
@@ -20,6 +20,56 @@ pub enum Error {
     InsufficientAllowance,
     /// Operation would cause an integer overflow.
     Overflow,
+    /// Contract was installed with an unknown events mode.
+    InvalidEventsMode,
+    /// Permit signature is malformed or was not made by the owner.
+    InvalidSignature,
+    /// Permit deadline has already passed.
+    PermitExpired,
+    /// Permit nonce does not match the owner's next nonce, e.g. because it was already used.
+    InvalidNonce,
+    /// Caller does not have the role required by the operation.
+    MissingRole,
+    /// Operation is not allowed while the contract is paused.
+    Paused,
+    /// Operation would increase the total supply above the cap.
+    CapExceeded,
+    /// Operation involves an address which has been frozen.
+    AccountFrozen,
+    /// Snapshot id does not refer to a snapshot which has already been taken.
+    InvalidSnapshotId,
+    /// Past votes were requested for a block time which has not passed yet.
+    FutureLookup,
+    /// Installed contract uses a storage layout which this version of the library can not use.
+    IncompatibleStorageVersion,
+    /// Lists passed to a batch operation have different lengths.
+    LengthMismatch,
+    /// Recipient of a transfer or approval with a call is not a contract, or it rejected the call.
+    ReceiverRejected,
+    /// Wrapped CSPR token was installed with decimals other than the decimals of motes.
+    InvalidDecimals,
+    /// Transfer fee would exceed the hard maximum.
+    TransferFeeTooHigh,
+    /// Rebase would set the total supply to zero while shares are still held, or to a non-zero
+    /// amount while no shares are held.
+    InvalidRebase,
+    /// Allowance has expired and can no longer be spent.
+    AllowanceExpired,
+    /// Caller is not the owner of the contract, or not its pending owner when accepting the
+    /// ownership.
+    NotOwner,
+    /// Minter does not have enough allowance left to mint.
+    MinterAllowanceExceeded,
+    /// Bridge transfer has already been minted.
+    BridgeTransferProcessed,
+    /// Bridge does not send tokens to the destination chain.
+    UnsupportedChain,
+    /// Bridged amount is below the minimum or above the maximum of the destination chain.
+    BridgeAmountOutOfRange,
+    /// Wrapped CSPR token was installed with an initial supply which is not backed by any CSPR.
+    UnbackedSupply,
+    /// Rebasing balances were combined with a feature which does not follow rebases.
+    RebasingNotSupported,
     /// User error.
     User(u16),
 }
@@ -28,6 +78,30 @@ const ERROR_INVALID_CONTEXT: u16 = u16::MAX;
 const ERROR_INSUFFICIENT_BALANCE: u16 = u16::MAX - 1;
 const ERROR_INSUFFICIENT_ALLOWANCE: u16 = u16::MAX - 2;
 const ERROR_OVERFLOW: u16 = u16::MAX - 3;
+const ERROR_INVALID_EVENTS_MODE: u16 = u16::MAX - 4;
+const ERROR_INVALID_SIGNATURE: u16 = u16::MAX - 5;
+const ERROR_PERMIT_EXPIRED: u16 = u16::MAX - 6;
+const ERROR_INVALID_NONCE: u16 = u16::MAX - 7;
+const ERROR_MISSING_ROLE: u16 = u16::MAX - 8;
+const ERROR_PAUSED: u16 = u16::MAX - 9;
+const ERROR_CAP_EXCEEDED: u16 = u16::MAX - 10;
+const ERROR_ACCOUNT_FROZEN: u16 = u16::MAX - 11;
+const ERROR_INVALID_SNAPSHOT_ID: u16 = u16::MAX - 12;
+const ERROR_FUTURE_LOOKUP: u16 = u16::MAX - 13;
+const ERROR_INCOMPATIBLE_STORAGE_VERSION: u16 = u16::MAX - 14;
+const ERROR_LENGTH_MISMATCH: u16 = u16::MAX - 15;
+const ERROR_RECEIVER_REJECTED: u16 = u16::MAX - 16;
+const ERROR_INVALID_DECIMALS: u16 = u16::MAX - 17;
+const ERROR_TRANSFER_FEE_TOO_HIGH: u16 = u16::MAX - 18;
+const ERROR_INVALID_REBASE: u16 = u16::MAX - 19;
+const ERROR_ALLOWANCE_EXPIRED: u16 = u16::MAX - 20;
+const ERROR_NOT_OWNER: u16 = u16::MAX - 21;
+const ERROR_MINTER_ALLOWANCE_EXCEEDED: u16 = u16::MAX - 22;
+const ERROR_BRIDGE_TRANSFER_PROCESSED: u16 = u16::MAX - 23;
+const ERROR_UNSUPPORTED_CHAIN: u16 = u16::MAX - 24;
+const ERROR_BRIDGE_AMOUNT_OUT_OF_RANGE: u16 = u16::MAX - 25;
+const ERROR_UNBACKED_SUPPLY: u16 = u16::MAX - 26;
+const ERROR_REBASING_NOT_SUPPORTED: u16 = u16::MAX - 27;
 
 impl From<Error> for ApiError {
     fn from(error: Error) -> Self {
@@ -36,6 +110,30 @@ impl From<Error> for ApiError {
             Error::InsufficientBalance => ERROR_INSUFFICIENT_BALANCE,
             Error::InsufficientAllowance => ERROR_INSUFFICIENT_ALLOWANCE,
             Error::Overflow => ERROR_OVERFLOW,
+            Error::InvalidEventsMode => ERROR_INVALID_EVENTS_MODE,
+            Error::InvalidSignature => ERROR_INVALID_SIGNATURE,
+            Error::PermitExpired => ERROR_PERMIT_EXPIRED,
+            Error::InvalidNonce => ERROR_INVALID_NONCE,
+            Error::MissingRole => ERROR_MISSING_ROLE,
+            Error::Paused => ERROR_PAUSED,
+            Error::CapExceeded => ERROR_CAP_EXCEEDED,
+            Error::AccountFrozen => ERROR_ACCOUNT_FROZEN,
+            Error::InvalidSnapshotId => ERROR_INVALID_SNAPSHOT_ID,
+            Error::FutureLookup => ERROR_FUTURE_LOOKUP,
+            Error::IncompatibleStorageVersion => ERROR_INCOMPATIBLE_STORAGE_VERSION,
+            Error::LengthMismatch => ERROR_LENGTH_MISMATCH,
+            Error::ReceiverRejected => ERROR_RECEIVER_REJECTED,
+            Error::InvalidDecimals => ERROR_INVALID_DECIMALS,
+            Error::TransferFeeTooHigh => ERROR_TRANSFER_FEE_TOO_HIGH,
+            Error::InvalidRebase => ERROR_INVALID_REBASE,
+            Error::AllowanceExpired => ERROR_ALLOWANCE_EXPIRED,
+            Error::NotOwner => ERROR_NOT_OWNER,
+            Error::MinterAllowanceExceeded => ERROR_MINTER_ALLOWANCE_EXCEEDED,
+            Error::BridgeTransferProcessed => ERROR_BRIDGE_TRANSFER_PROCESSED,
+            Error::UnsupportedChain => ERROR_UNSUPPORTED_CHAIN,
+            Error::BridgeAmountOutOfRange => ERROR_BRIDGE_AMOUNT_OUT_OF_RANGE,
+            Error::UnbackedSupply => ERROR_UNBACKED_SUPPLY,
+            Error::RebasingNotSupported => ERROR_REBASING_NOT_SUPPORTED,
             Error::User(user_error) => user_error,
         };
         ApiError::User(user_error)
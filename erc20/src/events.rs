@@ -0,0 +1,260 @@
+//! Implementation of events.
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+};
+use core::convert::TryFrom;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{contracts::NamedKeys, Key, URef, U256};
+
+use crate::{
+    ces,
+    constants::{EVENTS_COUNT_KEY_NAME, EVENTS_KEY_NAME, EVENTS_MODE_KEY_NAME},
+    detail,
+    error::Error,
+    Address,
+};
+
+/// Selects the way the contract records events.
+#[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum EventsMode {
+    /// No events are recorded.
+    NoEvents = 0,
+    /// Events are recorded in the `events` dictionary under consecutive indices, with the number
+    /// of recorded events stored under the `events_count` named key.
+    Native = 1,
+    /// Events are recorded according to the Casper Event Standard, in the `__events` dictionary
+    /// described by the schema stored under `__events_schema`.
+    CES = 2,
+}
+
+impl Default for EventsMode {
+    fn default() -> Self {
+        EventsMode::Native
+    }
+}
+
+impl TryFrom<u8> for EventsMode {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(EventsMode::NoEvents),
+            1 => Ok(EventsMode::Native),
+            2 => Ok(EventsMode::CES),
+            _ => Err(Error::InvalidEventsMode),
+        }
+    }
+}
+
+/// An event describing a single change of balances or allowances.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum Event {
+    /// Tokens were moved from `sender` to `recipient`.
+    Transfer {
+        /// Address the tokens were taken from.
+        sender: Address,
+        /// Address the tokens were given to.
+        recipient: Address,
+        /// Amount of tokens moved.
+        amount: U256,
+    },
+    /// Allowance of `spender` over `owner`'s tokens was set to `amount`.
+    Approval {
+        /// Owner of the tokens.
+        owner: Address,
+        /// Address allowed to spend the tokens.
+        spender: Address,
+        /// New allowance.
+        amount: U256,
+    },
+    /// New tokens were created and given to `recipient`.
+    Mint {
+        /// Address the new tokens were given to.
+        recipient: Address,
+        /// Amount of tokens created.
+        amount: U256,
+    },
+    /// Tokens were destroyed from `owner`'s balance.
+    Burn {
+        /// Address the tokens were taken from.
+        owner: Address,
+        /// Amount of tokens destroyed.
+        amount: U256,
+    },
+    /// Total supply was changed from `previous_total_supply` to `new_total_supply`, changing the
+    /// balance of every holder in the same proportion.
+    Rebase {
+        /// Total supply before the rebase.
+        previous_total_supply: U256,
+        /// Total supply after the rebase.
+        new_total_supply: U256,
+    },
+}
+
+fn address_to_string(address: Address) -> String {
+    Key::from(address).to_formatted_string()
+}
+
+impl Event {
+    /// Returns the name of the event.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Event::Transfer { .. } => "transfer",
+            Event::Approval { .. } => "approval",
+            Event::Mint { .. } => "mint",
+            Event::Burn { .. } => "burn",
+            Event::Rebase { .. } => "rebase",
+        }
+    }
+
+    /// Converts the event into a map of strings which can be read by any off-chain client without
+    /// knowledge of the event layout.
+    fn to_map(&self) -> BTreeMap<String, String> {
+        let mut map = BTreeMap::new();
+        map.insert("event_type".to_string(), self.name().to_string());
+        match self {
+            Event::Transfer {
+                sender,
+                recipient,
+                amount,
+            } => {
+                map.insert("sender".to_string(), address_to_string(*sender));
+                map.insert("recipient".to_string(), address_to_string(*recipient));
+                map.insert("amount".to_string(), amount.to_string());
+            }
+            Event::Approval {
+                owner,
+                spender,
+                amount,
+            } => {
+                map.insert("owner".to_string(), address_to_string(*owner));
+                map.insert("spender".to_string(), address_to_string(*spender));
+                map.insert("amount".to_string(), amount.to_string());
+            }
+            Event::Mint { recipient, amount } => {
+                map.insert("recipient".to_string(), address_to_string(*recipient));
+                map.insert("amount".to_string(), amount.to_string());
+            }
+            Event::Burn { owner, amount } => {
+                map.insert("owner".to_string(), address_to_string(*owner));
+                map.insert("amount".to_string(), amount.to_string());
+            }
+            Event::Rebase {
+                previous_total_supply,
+                new_total_supply,
+            } => {
+                map.insert(
+                    "previous_total_supply".to_string(),
+                    previous_total_supply.to_string(),
+                );
+                map.insert("new_total_supply".to_string(), new_total_supply.to_string());
+            }
+        }
+        map
+    }
+}
+
+/// Storage of the events, as selected by the events mode the contract was installed with.
+#[derive(Clone, Copy)]
+pub(crate) enum Events {
+    /// No events are recorded.
+    NoEvents,
+    /// Events are recorded in the `events` dictionary.
+    Native {
+        events_uref: URef,
+        events_count_uref: URef,
+    },
+    /// Events are recorded in the CES events dictionary.
+    CES {
+        events_uref: URef,
+        events_length_uref: URef,
+    },
+}
+
+impl Events {
+    /// Gets the storage of the events the contract was installed with.
+    pub(crate) fn get() -> Self {
+        let events_mode: u8 = detail::read_from(EVENTS_MODE_KEY_NAME);
+        match EventsMode::try_from(events_mode).unwrap_or_revert() {
+            EventsMode::NoEvents => Events::NoEvents,
+            EventsMode::Native => Events::Native {
+                events_uref: detail::get_uref(EVENTS_KEY_NAME),
+                events_count_uref: detail::get_uref(EVENTS_COUNT_KEY_NAME),
+            },
+            EventsMode::CES => {
+                let (events_uref, events_length_uref) = ces::get_events_urefs();
+                Events::CES {
+                    events_uref,
+                    events_length_uref,
+                }
+            }
+        }
+    }
+
+    /// Records an event.
+    pub(crate) fn record(&self, event: Event) {
+        match *self {
+            Events::NoEvents => {}
+            Events::Native {
+                events_uref,
+                events_count_uref,
+            } => write_event_to(events_uref, events_count_uref, event),
+            Events::CES {
+                events_uref,
+                events_length_uref,
+            } => ces::write_event_to(events_uref, events_length_uref, event),
+        }
+    }
+}
+
+/// Writes an event under the next free index of the events dictionary.
+fn write_event_to(events_uref: URef, events_count_uref: URef, event: Event) {
+    let events_count: u64 = storage::read(events_count_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    storage::dictionary_put(events_uref, &events_count.to_string(), event.to_map());
+    storage::write(events_count_uref, events_count + 1);
+}
+
+/// Sets up the storage required by `events_mode`, recording `initial_event` as the first event.
+pub(crate) fn install(
+    events_mode: EventsMode,
+    initial_event: Event,
+    named_keys: &mut NamedKeys,
+) -> Events {
+    match events_mode {
+        EventsMode::NoEvents => Events::NoEvents,
+        EventsMode::Native => {
+            let events_uref = storage::new_dictionary(EVENTS_KEY_NAME).unwrap_or_revert();
+            let events_count_uref = storage::new_uref(0u64).into_read_write();
+
+            write_event_to(events_uref, events_count_uref, initial_event);
+
+            runtime::remove_key(EVENTS_KEY_NAME);
+
+            named_keys.insert(EVENTS_KEY_NAME.to_string(), Key::from(events_uref));
+            named_keys.insert(
+                EVENTS_COUNT_KEY_NAME.to_string(),
+                Key::from(events_count_uref),
+            );
+
+            Events::Native {
+                events_uref,
+                events_count_uref,
+            }
+        }
+        EventsMode::CES => {
+            let (events_uref, events_length_uref) = ces::install(initial_event, named_keys);
+            Events::CES {
+                events_uref,
+                events_length_uref,
+            }
+        }
+    }
+}
@@ -0,0 +1,94 @@
+//! Implementation of flash mints, following ERC-3156.
+//!
+//! A borrower contract receives freshly minted tokens and a call to its [`on_flash_loan`] entry
+//! point. Before that call returns, the borrower has to approve the token contract to spend the
+//! loan plus the fee, which are then burned.
+use alloc::{string::String, vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::Bytes, runtime_args, CLTyped, EntryPoint, EntryPointAccess, EntryPointType,
+    Parameter, RuntimeArgs, U256,
+};
+
+use crate::{
+    constants::{
+        AMOUNT_RUNTIME_ARG_NAME, BASIS_POINTS, DATA_RUNTIME_ARG_NAME, FEE_RUNTIME_ARG_NAME,
+        FLASH_FEE_BASIS_POINTS_KEY_NAME, INITIATOR_RUNTIME_ARG_NAME,
+        ON_FLASH_LOAN_ENTRY_POINT_NAME,
+    },
+    detail,
+    error::Error,
+    Address,
+};
+
+/// Returns the `on_flash_loan` entry point, called on the borrower after `amount` of tokens were
+/// minted to it on behalf of `initiator`.
+///
+/// The borrower returns `true` once it has approved the token contract to spend `amount` plus
+/// `fee` of its tokens.
+pub fn on_flash_loan() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ON_FLASH_LOAN_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(INITIATOR_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(FEE_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(DATA_RUNTIME_ARG_NAME, Bytes::cl_type()),
+        ],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns `true` if the contract was installed with flash mints.
+pub(crate) fn is_enabled() -> bool {
+    detail::get_optional_uref(FLASH_FEE_BASIS_POINTS_KEY_NAME).is_some()
+}
+
+/// Returns the fee charged for a flash loan of `amount` tokens.
+pub(crate) fn flash_fee(amount: U256) -> Result<U256, Error> {
+    let flash_fee_basis_points_uref = detail::get_uref(FLASH_FEE_BASIS_POINTS_KEY_NAME);
+    let flash_fee_basis_points: u16 = storage::read(flash_fee_basis_points_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    let fee = amount
+        .checked_mul(U256::from(flash_fee_basis_points))
+        .ok_or(Error::Overflow)?
+        / U256::from(BASIS_POINTS);
+    Ok(fee)
+}
+
+/// Calls [`on_flash_loan`] on the `borrower` contract and checks whether it succeeded.
+pub(crate) fn notify_borrower(
+    borrower: Address,
+    initiator: Address,
+    amount: U256,
+    fee: U256,
+    data: Bytes,
+) -> Result<(), Error> {
+    let borrower_package_hash = match borrower {
+        Address::Contract(contract_package_hash) => contract_package_hash,
+        Address::Account(_) => return Err(Error::ReceiverRejected),
+    };
+    let succeeded: bool = runtime::call_versioned_contract(
+        borrower_package_hash,
+        None,
+        ON_FLASH_LOAN_ENTRY_POINT_NAME,
+        runtime_args! {
+            INITIATOR_RUNTIME_ARG_NAME => initiator,
+            AMOUNT_RUNTIME_ARG_NAME => amount,
+            FEE_RUNTIME_ARG_NAME => fee,
+            DATA_RUNTIME_ARG_NAME => data,
+        },
+    );
+    if succeeded {
+        Ok(())
+    } else {
+        Err(Error::ReceiverRejected)
+    }
+}
@@ -0,0 +1,126 @@
+//! Implementation of the index of holders, which allows enumerating them on-chain.
+//!
+//! Holders are stored in the `holders` dictionary under consecutive indices, and the index of each
+//! of them is stored in the `holder_indices` dictionary under its address. An address is added at
+//! the end of the list when its balance becomes non-zero, and once its balance drops back to zero
+//! the last holder is moved into its place, so the list never has gaps.
+use alloc::{string::ToString, vec::Vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{contracts::NamedKeys, Key, URef, U256};
+
+use crate::{
+    constants::{HOLDERS_KEY_NAME, HOLDER_COUNT_KEY_NAME, HOLDER_INDICES_KEY_NAME},
+    detail::{self, make_dictionary_item_key},
+    Address,
+};
+
+/// Storage of the index of holders.
+#[derive(Clone, Copy)]
+pub(crate) struct Holders {
+    holder_count_uref: URef,
+    holders_uref: URef,
+    holder_indices_uref: URef,
+}
+
+impl Holders {
+    /// Gets the storage of the index, if the contract was installed with holder enumeration.
+    pub(crate) fn get() -> Option<Self> {
+        let holder_count_uref = detail::get_optional_uref(HOLDER_COUNT_KEY_NAME)?;
+        Some(Holders {
+            holder_count_uref,
+            holders_uref: detail::get_uref(HOLDERS_KEY_NAME),
+            holder_indices_uref: detail::get_uref(HOLDER_INDICES_KEY_NAME),
+        })
+    }
+
+    /// Reads the number of addresses with a non-zero balance.
+    pub(crate) fn read_holder_count(&self) -> u64 {
+        storage::read(self.holder_count_uref)
+            .unwrap_or_revert()
+            .unwrap_or_revert()
+    }
+
+    /// Reads at most `limit` holders, starting with the one at `offset`.
+    pub(crate) fn read_holders(&self, offset: u64, limit: u64) -> Vec<Address> {
+        let holder_count = self.read_holder_count();
+        let end = offset.saturating_add(limit).min(holder_count);
+        (offset..end).map(|index| self.read_holder(index)).collect()
+    }
+
+    fn read_holder(&self, index: u64) -> Address {
+        storage::dictionary_get(self.holders_uref, &index.to_string())
+            .unwrap_or_revert()
+            .unwrap_or_revert()
+    }
+
+    fn write_holder(&self, index: u64, holder: Address) {
+        storage::dictionary_put(self.holders_uref, &index.to_string(), holder);
+        storage::dictionary_put(
+            self.holder_indices_uref,
+            &make_dictionary_item_key(holder),
+            index,
+        );
+    }
+
+    /// Updates the index after the balance of `address` changed from `previous_balance` to
+    /// `new_balance`.
+    pub(crate) fn update(&self, address: Address, previous_balance: U256, new_balance: U256) {
+        match (previous_balance.is_zero(), new_balance.is_zero()) {
+            (true, false) => self.add(address),
+            (false, true) => self.remove(address),
+            _ => {}
+        }
+    }
+
+    fn add(&self, holder: Address) {
+        let holder_count = self.read_holder_count();
+        self.write_holder(holder_count, holder);
+        storage::write(self.holder_count_uref, holder_count + 1);
+    }
+
+    fn remove(&self, holder: Address) {
+        let index: u64 =
+            storage::dictionary_get(self.holder_indices_uref, &make_dictionary_item_key(holder))
+                .unwrap_or_revert()
+                .unwrap_or_revert();
+        let last_index = self.read_holder_count() - 1;
+        if index != last_index {
+            let last_holder = self.read_holder(last_index);
+            self.write_holder(index, last_holder);
+        }
+        // Entries past the end of the list, and the stale index of a removed holder, are never
+        // read, so they are simply overwritten once they are needed again.
+        storage::write(self.holder_count_uref, last_index);
+    }
+
+    /// Sets up an empty index of holders.
+    pub(crate) fn install(named_keys: &mut NamedKeys) -> Self {
+        let holder_count_uref = storage::new_uref(0u64).into_read_write();
+        let holders_uref = storage::new_dictionary(HOLDERS_KEY_NAME).unwrap_or_revert();
+        let holder_indices_uref =
+            storage::new_dictionary(HOLDER_INDICES_KEY_NAME).unwrap_or_revert();
+
+        runtime::remove_key(HOLDERS_KEY_NAME);
+        runtime::remove_key(HOLDER_INDICES_KEY_NAME);
+
+        named_keys.insert(
+            HOLDER_COUNT_KEY_NAME.to_string(),
+            Key::from(holder_count_uref),
+        );
+        named_keys.insert(HOLDERS_KEY_NAME.to_string(), Key::from(holders_uref));
+        named_keys.insert(
+            HOLDER_INDICES_KEY_NAME.to_string(),
+            Key::from(holder_indices_uref),
+        );
+
+        Holders {
+            holder_count_uref,
+            holders_uref,
+            holder_indices_uref,
+        }
+    }
+}
@@ -15,16 +15,40 @@
 
 extern crate alloc;
 
+mod access_control;
 mod address;
 mod allowances;
 mod balances;
+mod blocklist;
+mod bridge;
+mod ces;
 pub mod constants;
 mod detail;
 pub mod entry_points;
 mod error;
+mod events;
+pub mod flash_mint;
+mod holders;
+mod metadata;
+mod minter_allowances;
+mod nonces;
+mod options;
+mod ownership;
+mod pausable;
+mod permit;
+mod rebasing;
+pub mod receiver;
+mod snapshots;
 mod total_supply;
+mod transfer_fee;
+mod votes;
+mod wrapped;
 
-use alloc::string::{String, ToString};
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
 
 use once_cell::unsync::OnceCell;
 
@@ -32,14 +56,31 @@ use casper_contract::{
     contract_api::{runtime, storage},
     unwrap_or_revert::UnwrapOrRevert,
 };
-use casper_types::{contracts::NamedKeys, EntryPoints, Key, URef, U256};
+use casper_types::{
+    bytesrepr::Bytes, contracts::NamedKeys, ApiError, EntryPoints, Key, PublicKey, RuntimeArgs,
+    URef, U256, U512,
+};
 
 pub use address::Address;
+use balances::BalanceTrackers;
+pub use bridge::{BridgeRequest, BridgeTransfer};
 use constants::{
-    ALLOWANCES_KEY_NAME, BALANCES_KEY_NAME, DECIMALS_KEY_NAME, ERC20_TOKEN_CONTRACT_KEY_NAME,
-    NAME_KEY_NAME, SYMBOL_KEY_NAME, TOTAL_SUPPLY_KEY_NAME,
+    ADMIN_ROLE, ALLOWANCES_KEY_NAME, BALANCES_KEY_NAME, BRIDGE_OPERATOR_ROLE, BURNER_ROLE,
+    CAP_KEY_NAME, DECIMALS_KEY_NAME, ERC20_TOKEN_CONTRACT_KEY_NAME, EVENTS_MODE_KEY_NAME,
+    FLASH_FEE_BASIS_POINTS_KEY_NAME, FROZEN_KEY_NAME, MINTER_ALLOWANCES_KEY_NAME, MINTER_ROLE,
+    NAME_KEY_NAME, NONCES_KEY_NAME, PAUSED_KEY_NAME, ROLES_KEY_NAME, STORAGE_VERSION,
+    STORAGE_VERSION_ENTRY_POINT_NAME, STORAGE_VERSION_KEY_NAME, SYMBOL_KEY_NAME,
+    TOTAL_SHARES_KEY_NAME, TOTAL_SUPPLY_KEY_NAME, WRAPPED_DECIMALS,
 };
 pub use error::Error;
+use events::Events;
+pub use events::{Event, EventsMode};
+use holders::Holders;
+pub use options::{InstallOptions, TransferFeeOptions, UpgradeOptions};
+use rebasing::Shares;
+use snapshots::Snapshots;
+use transfer_fee::Fee;
+use votes::Votes;
 
 /// Implementation of ERC20 standard functionality.
 #[derive(Default)]
@@ -47,17 +88,17 @@ pub struct ERC20 {
     balances_uref: OnceCell<URef>,
     allowances_uref: OnceCell<URef>,
     total_supply_uref: OnceCell<URef>,
+    nonces_uref: OnceCell<URef>,
+    frozen_uref: OnceCell<URef>,
+    total_shares_uref: OnceCell<Option<URef>>,
+    holder_index: OnceCell<Option<Holders>>,
+    events: OnceCell<Events>,
+    cap: OnceCell<Option<U256>>,
+    snapshots: OnceCell<Option<Snapshots>>,
+    votes: OnceCell<Option<Votes>>,
 }
 
 impl ERC20 {
-    fn new(balances_uref: URef, allowances_uref: URef, total_supply_uref: URef) -> Self {
-        Self {
-            balances_uref: balances_uref.into(),
-            allowances_uref: allowances_uref.into(),
-            total_supply_uref: total_supply_uref.into(),
-        }
-    }
-
     fn total_supply_uref(&self) -> URef {
         *self
             .total_supply_uref
@@ -69,7 +110,11 @@ impl ERC20 {
     }
 
     fn write_total_supply(&self, total_supply: U256) {
-        total_supply::write_total_supply_to(self.total_supply_uref(), total_supply)
+        total_supply::write_total_supply_to(
+            self.total_supply_uref(),
+            self.snapshots(),
+            total_supply,
+        )
     }
 
     fn balances_uref(&self) -> URef {
@@ -81,7 +126,7 @@ impl ERC20 {
     }
 
     fn write_balance(&mut self, owner: Address, amount: U256) {
-        balances::write_balance_to(self.balances_uref(), owner, amount)
+        balances::write_balance_to(self.balances_uref(), self.balance_trackers(), owner, amount)
     }
 
     fn allowances_uref(&self) -> URef {
@@ -98,16 +143,183 @@ impl ERC20 {
         allowances::write_allowance_to(self.allowances_uref(), owner, spender, amount)
     }
 
+    fn read_allowance_expiry(&self, owner: Address, spender: Address) -> Option<u64> {
+        allowances::read_allowance_expiry_from(self.allowances_uref(), owner, spender)
+    }
+
+    fn write_allowance_expiry(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        expires_at: Option<u64>,
+    ) {
+        allowances::write_allowance_expiry_to(self.allowances_uref(), owner, spender, expires_at)
+    }
+
+    /// Reads the allowance of `spender` over `owner`'s tokens, failing if it has expired.
+    fn read_spendable_allowance(&self, owner: Address, spender: Address) -> Result<U256, Error> {
+        match self.read_allowance_expiry(owner, spender) {
+            Some(expires_at) if allowances::is_expired(expires_at) => Err(Error::AllowanceExpired),
+            _ => Ok(self.read_allowance(owner, spender)),
+        }
+    }
+
+    /// Reads the allowance of `spender` over `owner`'s tokens together with its expiry. An expired
+    /// allowance is read as zero with no expiry.
+    fn read_current_allowance(&self, owner: Address, spender: Address) -> (U256, Option<u64>) {
+        match self.read_allowance_expiry(owner, spender) {
+            Some(expires_at) if allowances::is_expired(expires_at) => (U256::zero(), None),
+            expires_at => (self.read_allowance(owner, spender), expires_at),
+        }
+    }
+
+    /// Sets the allowance of `spender` over `owner`'s tokens together with its expiry, and records
+    /// the approval.
+    fn write_approval(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        amount: U256,
+        expires_at: Option<u64>,
+    ) {
+        self.write_allowance(owner, spender, amount);
+        // Most allowances never expire, so the expiry is only written if there is one to set or to
+        // clear.
+        if expires_at.is_some() || self.read_allowance_expiry(owner, spender).is_some() {
+            self.write_allowance_expiry(owner, spender, expires_at);
+        }
+        self.record_event(Event::Approval {
+            owner,
+            spender,
+            amount,
+        });
+    }
+
+    fn nonces_uref(&self) -> URef {
+        *self.nonces_uref.get_or_init(nonces::nonces_uref)
+    }
+
+    fn read_nonce(&self, owner: Address) -> u64 {
+        nonces::read_nonce_from(self.nonces_uref(), owner)
+    }
+
+    fn write_nonce(&mut self, owner: Address, nonce: u64) {
+        nonces::write_nonce_to(self.nonces_uref(), owner, nonce)
+    }
+
+    fn frozen_uref(&self) -> URef {
+        *self.frozen_uref.get_or_init(blocklist::frozen_uref)
+    }
+
+    fn ensure_not_frozen(&self, addresses: &[Address]) -> Result<(), Error> {
+        blocklist::ensure_not_frozen(self.frozen_uref(), addresses)
+    }
+
+    fn total_shares_uref(&self) -> Option<URef> {
+        *self
+            .total_shares_uref
+            .get_or_init(rebasing::total_shares_uref)
+    }
+
+    /// Returns the value of a share if balances are stored as shares of the total supply.
+    fn shares(&self) -> Option<Shares> {
+        self.total_shares_uref()
+            .map(|total_shares_uref| Shares::read_from(total_shares_uref, self.read_total_supply()))
+    }
+
+    fn holder_index(&self) -> Option<Holders> {
+        *self.holder_index.get_or_init(Holders::get)
+    }
+
+    fn snapshots(&self) -> Option<Snapshots> {
+        *self.snapshots.get_or_init(Snapshots::get)
+    }
+
+    fn votes(&self) -> Option<Votes> {
+        *self.votes.get_or_init(Votes::get)
+    }
+
+    /// Moves voting power of `amount` tokens sent from `sender` to `recipient` between their
+    /// delegatees, if the contract was installed with votes.
+    fn move_delegate_votes(
+        &self,
+        sender: Option<Address>,
+        recipient: Option<Address>,
+        amount: U256,
+    ) -> Result<(), Error> {
+        match self.votes() {
+            Some(votes) => votes.move_delegate_votes(sender, recipient, amount),
+            None => Ok(()),
+        }
+    }
+
+    fn balance_trackers(&self) -> BalanceTrackers {
+        BalanceTrackers {
+            snapshots: self.snapshots(),
+            holders: self.holder_index(),
+        }
+    }
+
+    /// Moves `amount` of tokens from `sender` to `recipient` and records the transfer, returning
+    /// the amount received by the recipient once the transfer fee was taken out.
     fn transfer_balance(
         &mut self,
         sender: Address,
         recipient: Address,
         amount: U256,
-    ) -> Result<(), Error> {
-        balances::transfer_balance(self.balances_uref(), sender, recipient, amount)
+    ) -> Result<U256, Error> {
+        let fee = balances::transfer_balance(
+            self.balances_uref(),
+            self.frozen_uref(),
+            self.shares(),
+            self.balance_trackers(),
+            sender,
+            recipient,
+            amount,
+        )?;
+        self.record_transfer(sender, recipient, amount, fee)
     }
 
-    /// Installs the ERC20 contract with the default set of entry points.
+    /// Moves votes and records events of a transfer whose balances were already written, returning
+    /// the amount received by the recipient.
+    fn record_transfer(
+        &mut self,
+        sender: Address,
+        recipient: Address,
+        amount: U256,
+        fee: Option<Fee>,
+    ) -> Result<U256, Error> {
+        let received = match &fee {
+            Some(fee) => amount - fee.amount,
+            None => amount,
+        };
+        self.move_delegate_votes(Some(sender), Some(recipient), received)?;
+        self.record_event(Event::Transfer {
+            sender,
+            recipient,
+            amount: received,
+        });
+        if let Some(Fee { treasury, amount }) = fee {
+            self.move_delegate_votes(Some(sender), Some(treasury), amount)?;
+            self.record_event(Event::Transfer {
+                sender,
+                recipient: treasury,
+                amount,
+            });
+        }
+        Ok(received)
+    }
+
+    fn events(&self) -> Events {
+        *self.events.get_or_init(Events::get)
+    }
+
+    fn record_event(&self, event: Event) {
+        self.events().record(event)
+    }
+
+    /// Installs the ERC20 contract with the default set of entry points and the default
+    /// [`InstallOptions`], which record an [`Event`] for every change of balances or allowances.
     ///
     /// This should be called from within `fn call()` of your contract.
     pub fn install(
@@ -124,6 +336,7 @@ impl ERC20 {
             initial_supply,
             ERC20_TOKEN_CONTRACT_KEY_NAME,
             default_entry_points,
+            InstallOptions::default(),
         )
     }
 
@@ -147,19 +360,111 @@ impl ERC20 {
         self.read_total_supply()
     }
 
+    /// Returns the maximum total supply of the token, if it was installed with one.
+    pub fn cap(&self) -> Option<U256> {
+        *self.cap.get_or_init(total_supply::read_cap)
+    }
+
     /// Returns the balance of `owner`.
+    ///
+    /// If the contract was installed with [`InstallOptions::rebasing`] set, this is the value of
+    /// `owner`'s shares, rounded down.
     pub fn balance_of(&self, owner: Address) -> U256 {
-        self.read_balance(owner)
+        let balance = self.read_balance(owner);
+        match self.shares() {
+            Some(shares) => shares.to_tokens(balance),
+            None => balance,
+        }
+    }
+
+    /// Returns the balance of `owner` at the time `snapshot_id` was taken.
+    ///
+    /// The contract has to be installed with [`InstallOptions::snapshots`] set.
+    pub fn balance_of_at(&self, owner: Address, snapshot_id: u64) -> Result<U256, Error> {
+        let snapshots = self.snapshots().ok_or(Error::InvalidSnapshotId)?;
+        let balance = snapshots.read_balance_at(owner, snapshot_id)?;
+        Ok(balance.unwrap_or_else(|| self.read_balance(owner)))
+    }
+
+    /// Returns the total supply of the token at the time `snapshot_id` was taken.
+    ///
+    /// The contract has to be installed with [`InstallOptions::snapshots`] set.
+    pub fn total_supply_at(&self, snapshot_id: u64) -> Result<U256, Error> {
+        let snapshots = self.snapshots().ok_or(Error::InvalidSnapshotId)?;
+        let total_supply = snapshots.read_total_supply_at(snapshot_id)?;
+        Ok(total_supply.unwrap_or_else(|| self.read_total_supply()))
+    }
+
+    /// Takes a snapshot of all balances and of the total supply if the direct caller has the admin
+    /// role, and returns its id.
+    ///
+    /// The contract has to be installed with [`InstallOptions::snapshots`] set.
+    pub fn snapshot(&mut self) -> Result<u64, Error> {
+        self.only_role(ADMIN_ROLE)?;
+        self.snapshots()
+            .ok_or(ApiError::MissingKey)
+            .unwrap_or_revert()
+            .snapshot()
     }
 
     /// Transfers `amount` of tokens from the direct caller to `recipient`.
     pub fn transfer(&mut self, recipient: Address, amount: U256) -> Result<(), Error> {
         let sender = detail::get_immediate_caller_address()?;
-        self.transfer_balance(sender, recipient, amount)
+        pausable::ensure_not_paused()?;
+        self.transfer_balance(sender, recipient, amount)?;
+        Ok(())
+    }
+
+    /// Transfers `amount` of tokens from the direct caller to the `recipient` contract, and then
+    /// calls its [`on_erc20_received`](receiver::on_erc20_received) entry point with `data`.
+    ///
+    /// Fails if the recipient is not a contract or if it rejects the tokens. The recipient is
+    /// notified of the amount it received, net of the transfer fee.
+    pub fn transfer_and_call(
+        &mut self,
+        recipient: Address,
+        amount: U256,
+        data: Bytes,
+    ) -> Result<(), Error> {
+        let sender = detail::get_immediate_caller_address()?;
+        pausable::ensure_not_paused()?;
+        let received = self.transfer_balance(sender, recipient, amount)?;
+        receiver::notify_received(recipient, sender, received, data)
+    }
+
+    /// Transfers `amounts` of tokens from the direct caller to the respective `recipients`.
+    ///
+    /// Either all of the transfers succeed, or no balance is changed.
+    pub fn batch_transfer(
+        &mut self,
+        recipients: Vec<Address>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        let sender = detail::get_immediate_caller_address()?;
+        pausable::ensure_not_paused()?;
+        if recipients.len() != amounts.len() {
+            return Err(Error::LengthMismatch);
+        }
+        let fees = balances::transfer_balances(
+            self.balances_uref(),
+            self.frozen_uref(),
+            self.shares(),
+            self.balance_trackers(),
+            sender,
+            &recipients,
+            &amounts,
+        )?;
+        for ((recipient, amount), fee) in recipients.into_iter().zip(amounts).zip(fees) {
+            self.record_transfer(sender, recipient, amount, fee)?;
+        }
+        Ok(())
     }
 
     /// Transfers `amount` of tokens from `owner` to `recipient` if the direct caller has been
     /// previously approved to spend the specified amount on behalf of the owner.
+    ///
+    /// The allowance is spent for the whole `amount`, including the transfer fee, and it can not be
+    /// spent once it has expired.
     pub fn transfer_from(
         &mut self,
         owner: Address,
@@ -167,28 +472,170 @@ impl ERC20 {
         amount: U256,
     ) -> Result<(), Error> {
         let spender = detail::get_immediate_caller_address()?;
+        pausable::ensure_not_paused()?;
+        self.ensure_not_frozen(&[spender])?;
         if amount.is_zero() {
             return Ok(());
         }
-        let spender_allowance = self.read_allowance(owner, spender);
+        let spender_allowance = self.read_spendable_allowance(owner, spender)?;
         let new_spender_allowance = spender_allowance
             .checked_sub(amount)
             .ok_or(Error::InsufficientAllowance)?;
         self.transfer_balance(owner, recipient, amount)?;
         self.write_allowance(owner, spender, new_spender_allowance);
+        self.record_event(Event::Approval {
+            owner,
+            spender,
+            amount: new_spender_allowance,
+        });
         Ok(())
     }
 
     /// Allows `spender` to transfer up to `amount` of the direct caller's tokens.
+    ///
+    /// The allowance never expires, even if it replaces one which did.
     pub fn approve(&mut self, spender: Address, amount: U256) -> Result<(), Error> {
         let owner = detail::get_immediate_caller_address()?;
-        self.write_allowance(owner, spender, amount);
+        pausable::ensure_not_paused()?;
+        self.ensure_not_frozen(&[owner, spender])?;
+        self.write_approval(owner, spender, amount, None);
+        Ok(())
+    }
+
+    /// Allows `spender` to transfer up to `amount` of the direct caller's tokens until the block
+    /// time, in milliseconds, reaches `expires_at`.
+    ///
+    /// Once expired, the allowance reads as zero and can no longer be spent.
+    pub fn approve_with_expiry(
+        &mut self,
+        spender: Address,
+        amount: U256,
+        expires_at: u64,
+    ) -> Result<(), Error> {
+        let owner = detail::get_immediate_caller_address()?;
+        pausable::ensure_not_paused()?;
+        self.ensure_not_frozen(&[owner, spender])?;
+        self.write_approval(owner, spender, amount, Some(expires_at));
+        Ok(())
+    }
+
+    /// Allows the `spender` contract to transfer up to `amount` of the direct caller's tokens, and
+    /// then calls its [`on_erc20_approved`](receiver::on_erc20_approved) entry point with `data`.
+    ///
+    /// Fails if the spender is not a contract or if it rejects the allowance.
+    pub fn approve_and_call(
+        &mut self,
+        spender: Address,
+        amount: U256,
+        data: Bytes,
+    ) -> Result<(), Error> {
+        let owner = detail::get_immediate_caller_address()?;
+        self.approve(spender, amount)?;
+        receiver::notify_approved(spender, owner, amount, data)
+    }
+
+    /// Increases the allowance of `spender` over the direct caller's tokens by `amount`.
+    ///
+    /// Unlike [`ERC20::approve`] this does not overwrite the allowance, so it is not prone to a
+    /// spender using both the old and the new allowance when it gets changed. The expiry of the
+    /// allowance, if any, is kept, unless the allowance has already expired, in which case it is
+    /// increased from zero and no longer expires.
+    pub fn increase_allowance(&mut self, spender: Address, amount: U256) -> Result<(), Error> {
+        let owner = detail::get_immediate_caller_address()?;
+        pausable::ensure_not_paused()?;
+        self.ensure_not_frozen(&[owner, spender])?;
+        let (allowance, expires_at) = self.read_current_allowance(owner, spender);
+        let new_allowance = allowance.checked_add(amount).ok_or(Error::Overflow)?;
+        self.write_approval(owner, spender, new_allowance, expires_at);
+        Ok(())
+    }
+
+    /// Decreases the allowance of `spender` over the direct caller's tokens by `amount`.
+    ///
+    /// The expiry of the allowance, if any, is kept. An allowance which has already expired is
+    /// treated as zero.
+    pub fn decrease_allowance(&mut self, spender: Address, amount: U256) -> Result<(), Error> {
+        let owner = detail::get_immediate_caller_address()?;
+        pausable::ensure_not_paused()?;
+        self.ensure_not_frozen(&[owner, spender])?;
+        let (allowance, expires_at) = self.read_current_allowance(owner, spender);
+        let new_allowance = allowance
+            .checked_sub(amount)
+            .ok_or(Error::InsufficientAllowance)?;
+        self.write_approval(owner, spender, new_allowance, expires_at);
+        Ok(())
+    }
+
+    /// Returns the next permit nonce of `owner`.
+    pub fn nonces(&self, owner: Address) -> u64 {
+        self.read_nonce(owner)
+    }
+
+    /// Returns the domain separator which ties permit signatures to this token contract package.
+    ///
+    /// This has to be called from within the token contract.
+    pub fn domain_separator(&self) -> Result<[u8; 32], Error> {
+        let contract_package_hash = detail::get_current_contract_package_hash()?;
+        Ok(permit::make_domain_separator(
+            &self.name(),
+            contract_package_hash,
+        ))
+    }
+
+    /// Allows `spender` to transfer up to `amount` of `owner`'s tokens, given a `signature` made
+    /// by `owner` over the permit digest.
+    ///
+    /// The digest is a blake2b hash of the [domain separator](ERC20::domain_separator) followed by
+    /// the serialized address of the owner's account, `spender`, `amount`, `nonce` and `deadline`.
+    /// `nonce` has to be equal to [`ERC20::nonces`] of the owner, and `deadline` is compared
+    /// against the block time in milliseconds. `signature` is a serialized
+    /// [`casper_types::Signature`].
+    ///
+    /// Anyone can submit the permit, hence the signature is the only authorization this method
+    /// requires.
+    pub fn permit(
+        &mut self,
+        owner: PublicKey,
+        spender: Address,
+        amount: U256,
+        deadline: u64,
+        nonce: u64,
+        signature: Vec<u8>,
+    ) -> Result<(), Error> {
+        pausable::ensure_not_paused()?;
+
+        let blocktime: u64 = runtime::get_blocktime().into();
+        if blocktime > deadline {
+            return Err(Error::PermitExpired);
+        }
+
+        let owner_address = Address::from(owner.to_account_hash());
+        self.ensure_not_frozen(&[owner_address, spender])?;
+        if nonce != self.read_nonce(owner_address) {
+            return Err(Error::InvalidNonce);
+        }
+        let new_nonce = nonce.checked_add(1).ok_or(Error::Overflow)?;
+
+        let digest = permit::make_permit_digest(
+            self.domain_separator()?,
+            owner_address,
+            spender,
+            amount,
+            nonce,
+            deadline,
+        );
+        permit::verify_signature(digest, &signature, &owner)?;
+
+        self.write_nonce(owner_address, new_nonce);
+        self.write_approval(owner_address, spender, amount, None);
         Ok(())
     }
 
-    /// Returns the amount of `owner`'s tokens allowed to be spent by `spender`.
+    /// Returns the amount of `owner`'s tokens allowed to be spent by `spender`, or zero if the
+    /// allowance has expired.
     pub fn allowance(&self, owner: Address, spender: Address) -> U256 {
-        self.read_allowance(owner, spender)
+        self.read_spendable_allowance(owner, spender)
+            .unwrap_or_default()
     }
 
     /// Mints `amount` new tokens and adds them to `owner`'s balance and to the token total supply.
@@ -196,18 +643,37 @@ impl ERC20 {
     /// # Security
     ///
     /// This offers no security whatsoever, hence it is advised to NOT expose this method through a
-    /// public entry point.
+    /// public entry point. Use [`ERC20::mint_as_minter`] instead.
     pub fn mint(&mut self, owner: Address, amount: U256) -> Result<(), Error> {
+        self.ensure_not_frozen(&[owner])?;
+        let shares = self.shares();
+        let minted = match &shares {
+            Some(shares) => shares.to_shares(amount)?,
+            None => amount,
+        };
         let new_balance = {
             let balance = self.read_balance(owner);
-            balance.checked_add(amount).ok_or(Error::Overflow)?
+            balance.checked_add(minted).ok_or(Error::Overflow)?
         };
         let new_total_supply = {
             let total_supply: U256 = self.read_total_supply();
             total_supply.checked_add(amount).ok_or(Error::Overflow)?
         };
+        total_supply::ensure_within_cap(new_total_supply, self.cap())?;
+        if let (Some(total_shares_uref), Some(shares)) = (self.total_shares_uref(), shares) {
+            let new_total_shares = shares
+                .total_shares()
+                .checked_add(minted)
+                .ok_or(Error::Overflow)?;
+            rebasing::write_total_shares_to(total_shares_uref, new_total_shares);
+        }
         self.write_balance(owner, new_balance);
         self.write_total_supply(new_total_supply);
+        self.move_delegate_votes(None, Some(owner), amount)?;
+        self.record_event(Event::Mint {
+            recipient: owner,
+            amount,
+        });
         Ok(())
     }
 
@@ -217,24 +683,633 @@ impl ERC20 {
     /// # Security
     ///
     /// This offers no security whatsoever, hence it is advised to NOT expose this method through a
-    /// public entry point.
+    /// public entry point. Use [`ERC20::burn_as_burner`] instead.
     pub fn burn(&mut self, owner: Address, amount: U256) -> Result<(), Error> {
+        let shares = self.shares();
+        let burned = match &shares {
+            Some(shares) => shares.to_shares_rounding_up(amount)?,
+            None => amount,
+        };
         let new_balance = {
             let balance = self.read_balance(owner);
             balance
-                .checked_sub(amount)
+                .checked_sub(burned)
                 .ok_or(Error::InsufficientBalance)?
         };
         let new_total_supply = {
             let total_supply = self.read_total_supply();
             total_supply.checked_sub(amount).ok_or(Error::Overflow)?
         };
+        if let (Some(total_shares_uref), Some(shares)) = (self.total_shares_uref(), shares) {
+            rebasing::write_total_shares_to(total_shares_uref, shares.total_shares() - burned);
+        }
         self.write_balance(owner, new_balance);
         self.write_total_supply(new_total_supply);
+        self.move_delegate_votes(Some(owner), None, amount)?;
+        self.record_event(Event::Burn { owner, amount });
+        Ok(())
+    }
+
+    /// Returns `true` if `address` has been granted `role`.
+    pub fn has_role(&self, role: &str, address: Address) -> bool {
+        access_control::read_role_from(access_control::roles_uref(), role, address)
+    }
+
+    /// Ensures that the direct caller has been granted `role`.
+    ///
+    /// This is the guard which should be called first by entry points restricted to a role.
+    pub fn only_role(&self, role: &str) -> Result<(), Error> {
+        let caller = detail::get_immediate_caller_address()?;
+        if self.has_role(role, caller) {
+            Ok(())
+        } else {
+            Err(Error::MissingRole)
+        }
+    }
+
+    /// Grants `role` to `address` if the direct caller has the admin role.
+    pub fn grant_role(&mut self, role: &str, address: Address) -> Result<(), Error> {
+        self.only_role(ADMIN_ROLE)?;
+        access_control::write_role_to(access_control::roles_uref(), role, address, true);
+        Ok(())
+    }
+
+    /// Revokes `role` of `address` if the direct caller has the admin role.
+    pub fn revoke_role(&mut self, role: &str, address: Address) -> Result<(), Error> {
+        self.only_role(ADMIN_ROLE)?;
+        access_control::write_role_to(access_control::roles_uref(), role, address, false);
+        Ok(())
+    }
+
+    /// Returns the owner of the contract, or `None` if the ownership was renounced.
+    ///
+    /// The owner holds the admin role, which is handed over together with the ownership and given
+    /// up when the ownership is renounced.
+    pub fn owner(&self) -> Option<Address> {
+        ownership::read_owner()
+    }
+
+    /// Returns the owner nominated by the current one, which has not accepted the ownership yet.
+    pub fn pending_owner(&self) -> Option<Address> {
+        ownership::read_pending_owner()
+    }
+
+    /// Ensures that the direct caller is the owner of the contract.
+    ///
+    /// This is the guard which should be called first by entry points restricted to the owner.
+    /// Administrative entry points are guarded by the admin role instead, which is kept in sync
+    /// with the ownership by [`ERC20::accept_ownership`] and [`ERC20::renounce_ownership`].
+    pub fn only_owner(&self) -> Result<(), Error> {
+        let caller = detail::get_immediate_caller_address()?;
+        if self.owner() == Some(caller) {
+            Ok(())
+        } else {
+            Err(Error::NotOwner)
+        }
+    }
+
+    /// Nominates `new_owner` as the next owner of the contract if the direct caller is the owner.
+    ///
+    /// The ownership only changes once `new_owner` calls [`ERC20::accept_ownership`], so it can
+    /// not be handed to an address which is unable to use it. Nominating another address replaces
+    /// the previous nomination.
+    pub fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), Error> {
+        self.only_owner()?;
+        ownership::write_pending_owner(Some(new_owner));
+        Ok(())
+    }
+
+    /// Makes the direct caller the owner of the contract if it was nominated by the current owner.
+    ///
+    /// The admin role is revoked from the previous owner and granted to the new one.
+    pub fn accept_ownership(&mut self) -> Result<(), Error> {
+        let caller = detail::get_immediate_caller_address()?;
+        if self.pending_owner() != Some(caller) {
+            return Err(Error::NotOwner);
+        }
+        let roles_uref = access_control::roles_uref();
+        if let Some(previous_owner) = self.owner() {
+            access_control::write_role_to(roles_uref, ADMIN_ROLE, previous_owner, false);
+        }
+        access_control::write_role_to(roles_uref, ADMIN_ROLE, caller, true);
+        ownership::write_owner(Some(caller));
+        ownership::write_pending_owner(None);
+        Ok(())
+    }
+
+    /// Leaves the contract without an owner if the direct caller is the owner, which disables
+    /// every entry point restricted to the owner for good.
+    ///
+    /// The admin role of the owner is revoked as well.
+    pub fn renounce_ownership(&mut self) -> Result<(), Error> {
+        self.only_owner()?;
+        let owner = detail::get_immediate_caller_address()?;
+        access_control::write_role_to(access_control::roles_uref(), ADMIN_ROLE, owner, false);
+        ownership::write_owner(None);
+        ownership::write_pending_owner(None);
+        Ok(())
+    }
+
+    /// Mints `amount` new tokens to `owner` if the direct caller has the minter role.
+    ///
+    /// This is the implementation of the guarded `mint` entry point. The minted amount is taken
+    /// out of the allowance the caller was configured with by [`ERC20::configure_minter`], so a
+    /// minter which was only granted the role can not mint anything. The installer is the only
+    /// minter which starts with an allowance, and it is unlimited.
+    pub fn mint_as_minter(&mut self, owner: Address, amount: U256) -> Result<(), Error> {
+        self.only_role(MINTER_ROLE)?;
+        let minter = detail::get_immediate_caller_address()?;
+        let minter_allowances_uref = minter_allowances::minter_allowances_uref();
+        let new_allowance =
+            minter_allowances::read_minter_allowance_from(minter_allowances_uref, minter)
+                .unwrap_or_default()
+                .checked_sub(amount)
+                .ok_or(Error::MinterAllowanceExceeded)?;
+        minter_allowances::write_minter_allowance_to(
+            minter_allowances_uref,
+            minter,
+            Some(new_allowance),
+        );
+        self.mint(owner, amount)
+    }
+
+    /// Grants the minter role to `minter` and allows it to mint up to `allowance` tokens, if the
+    /// direct caller has the admin role.
+    ///
+    /// Configuring a minter again replaces its remaining allowance.
+    pub fn configure_minter(&mut self, minter: Address, allowance: U256) -> Result<(), Error> {
+        self.only_role(ADMIN_ROLE)?;
+        access_control::write_role_to(access_control::roles_uref(), MINTER_ROLE, minter, true);
+        minter_allowances::write_minter_allowance_to(
+            minter_allowances::minter_allowances_uref(),
+            minter,
+            Some(allowance),
+        );
+        Ok(())
+    }
+
+    /// Revokes the minter role of `minter` together with its allowance, if the direct caller has
+    /// the admin role.
+    pub fn remove_minter(&mut self, minter: Address) -> Result<(), Error> {
+        self.only_role(ADMIN_ROLE)?;
+        access_control::write_role_to(access_control::roles_uref(), MINTER_ROLE, minter, false);
+        minter_allowances::write_minter_allowance_to(
+            minter_allowances::minter_allowances_uref(),
+            minter,
+            None,
+        );
+        Ok(())
+    }
+
+    /// Returns the amount of tokens `minter` is still allowed to mint.
+    ///
+    /// This is zero for minters which were not configured with [`ERC20::configure_minter`], other
+    /// than the installer, whose allowance is unlimited until it is configured or removed.
+    pub fn minter_allowance(&self, minter: Address) -> U256 {
+        minter_allowances::read_minter_allowance_from(
+            minter_allowances::minter_allowances_uref(),
+            minter,
+        )
+        .unwrap_or_default()
+    }
+
+    /// Burns `amount` of `owner`'s tokens if the direct caller has the burner role.
+    ///
+    /// This is the implementation of the guarded `burn` entry point.
+    pub fn burn_as_burner(&mut self, owner: Address, amount: U256) -> Result<(), Error> {
+        self.only_role(BURNER_ROLE)?;
+        self.burn(owner, amount)
+    }
+
+    /// Returns `true` if the contract is pausable and it is currently paused.
+    pub fn paused(&self) -> bool {
+        pausable::is_paused()
+    }
+
+    /// Pauses transfers and approvals if the direct caller has the admin role.
+    ///
+    /// The contract has to be installed with [`InstallOptions::pausable`] set.
+    pub fn pause(&mut self) -> Result<(), Error> {
+        self.only_role(ADMIN_ROLE)?;
+        pausable::write_paused(true);
+        Ok(())
+    }
+
+    /// Resumes transfers and approvals if the direct caller has the admin role.
+    ///
+    /// The contract has to be installed with [`InstallOptions::pausable`] set.
+    pub fn unpause(&mut self) -> Result<(), Error> {
+        self.only_role(ADMIN_ROLE)?;
+        pausable::write_paused(false);
+        Ok(())
+    }
+
+    /// Returns `true` if `address` has been frozen.
+    pub fn is_frozen(&self, address: Address) -> bool {
+        blocklist::read_frozen_from(self.frozen_uref(), address)
+    }
+
+    /// Freezes `address` if the direct caller has the admin role.
+    ///
+    /// A frozen address can neither send nor receive tokens, and it can neither approve nor be
+    /// approved to spend them.
+    pub fn freeze(&mut self, address: Address) -> Result<(), Error> {
+        self.only_role(ADMIN_ROLE)?;
+        blocklist::write_frozen_to(self.frozen_uref(), address, true);
+        Ok(())
+    }
+
+    /// Unfreezes `address` if the direct caller has the admin role.
+    pub fn unfreeze(&mut self, address: Address) -> Result<(), Error> {
+        self.only_role(ADMIN_ROLE)?;
+        blocklist::write_frozen_to(self.frozen_uref(), address, false);
+        Ok(())
+    }
+
+    /// Delegates the voting power of the direct caller's tokens to `delegatee`.
+    ///
+    /// The contract has to be installed with [`InstallOptions::votes`] set.
+    pub fn delegate(&mut self, delegatee: Address) -> Result<(), Error> {
+        let delegator = detail::get_immediate_caller_address()?;
+        self.votes()
+            .ok_or(ApiError::MissingKey)
+            .unwrap_or_revert()
+            .delegate(delegator, delegatee, self.balance_of(delegator))
+    }
+
+    /// Returns the address `account` has delegated its voting power to, if any.
+    ///
+    /// The contract has to be installed with [`InstallOptions::votes`] set.
+    pub fn delegates(&self, account: Address) -> Option<Address> {
+        self.votes()
+            .ok_or(ApiError::MissingKey)
+            .unwrap_or_revert()
+            .read_delegate(account)
+    }
+
+    /// Returns the current voting power of `account`.
+    ///
+    /// The contract has to be installed with [`InstallOptions::votes`] set.
+    pub fn get_votes(&self, account: Address) -> U256 {
+        self.votes()
+            .ok_or(ApiError::MissingKey)
+            .unwrap_or_revert()
+            .read_votes(account)
+    }
+
+    /// Returns the voting power `account` had at `block_time`, given in milliseconds.
+    ///
+    /// `block_time` has to be earlier than the current block time, as the voting power could still
+    /// change within the current block. The contract has to be installed with
+    /// [`InstallOptions::votes`] set.
+    pub fn get_past_votes(&self, account: Address, block_time: u64) -> Result<U256, Error> {
+        self.votes()
+            .ok_or(ApiError::MissingKey)
+            .unwrap_or_revert()
+            .read_past_votes(account, block_time)
+    }
+
+    /// Returns the version of the storage layout the contract was installed with.
+    ///
+    /// Contracts installed before the version was recorded report 0.
+    pub fn storage_version(&self) -> u32 {
+        match detail::get_optional_uref(STORAGE_VERSION_KEY_NAME) {
+            Some(storage_version_uref) => storage::read(storage_version_uref)
+                .unwrap_or_revert()
+                .unwrap_or_revert(),
+            None => 0,
+        }
+    }
+
+    /// Adds a new version of an upgradable contract, installed with
+    /// [`InstallOptions::upgradable`], and stores its hash under `contract_key_name`.
+    ///
+    /// This should be called from within `fn call()` of the session code, signed by the account
+    /// which holds the package hash under `package_hash_key_name` together with the access URef of
+    /// the package. The current version of the contract has to expose the
+    /// [`storage_version`](entry_points::storage_version) entry point, and its storage layout has
+    /// to match [`STORAGE_VERSION`](constants::STORAGE_VERSION).
+    ///
+    /// The new version inherits all of the named keys of the current one, so it keeps using the
+    /// existing `balances`, `allowances` and `total_supply`. The current version, whose hash is
+    /// stored under `contract_key_name`, is disabled so that it can no longer be called.
+    pub fn upgrade(
+        package_hash_key_name: &str,
+        contract_key_name: &str,
+        entry_points: EntryPoints,
+    ) -> Result<(), Error> {
+        let contract_package_hash = detail::get_contract_package_hash(package_hash_key_name);
+
+        let storage_version: u32 = runtime::call_versioned_contract(
+            contract_package_hash,
+            None,
+            STORAGE_VERSION_ENTRY_POINT_NAME,
+            RuntimeArgs::new(),
+        );
+        if storage_version != STORAGE_VERSION {
+            return Err(Error::IncompatibleStorageVersion);
+        }
+
+        let previous_contract_hash = detail::get_contract_hash(contract_key_name);
+
+        let (contract_hash, _version) =
+            storage::add_contract_version(contract_package_hash, entry_points, NamedKeys::new());
+
+        storage::disable_contract_version(contract_package_hash, previous_contract_hash)
+            .unwrap_or_revert();
+
+        runtime::put_key(contract_key_name, Key::from(contract_hash));
+
+        Ok(())
+    }
+
+    /// Returns the maximum amount of tokens available for a flash loan, or 0 if the contract was
+    /// installed without flash mints.
+    pub fn max_flash_loan(&self) -> U256 {
+        if !flash_mint::is_enabled() {
+            return U256::zero();
+        }
+        let max_total_supply = self.cap().unwrap_or_else(U256::max_value);
+        max_total_supply.saturating_sub(self.read_total_supply())
+    }
+
+    /// Returns the fee charged for a flash loan of `amount` tokens.
+    ///
+    /// The contract has to be installed with [`InstallOptions::flash_fee_basis_points`] set.
+    pub fn flash_fee(&self, amount: U256) -> Result<U256, Error> {
+        flash_mint::flash_fee(amount)
+    }
+
+    /// Mints `amount` tokens to the `receiver` contract, calls its
+    /// [`on_flash_loan`](flash_mint::on_flash_loan) entry point with `data`, and then burns
+    /// `amount` plus the [fee](ERC20::flash_fee) from the receiver's balance.
+    ///
+    /// The receiver has to approve this contract to spend the tokens it owes before its entry
+    /// point returns.
+    pub fn flash_loan(
+        &mut self,
+        receiver: Address,
+        amount: U256,
+        data: Bytes,
+    ) -> Result<(), Error> {
+        let initiator = detail::get_immediate_caller_address()?;
+        pausable::ensure_not_paused()?;
+        let fee = self.flash_fee(amount)?;
+        let repayment = amount.checked_add(fee).ok_or(Error::Overflow)?;
+
+        self.mint(receiver, amount)?;
+        flash_mint::notify_borrower(receiver, initiator, amount, fee, data)?;
+
+        let token = Address::from(detail::get_current_contract_package_hash()?);
+        let new_allowance = {
+            let allowance = self.read_spendable_allowance(receiver, token)?;
+            allowance
+                .checked_sub(repayment)
+                .ok_or(Error::InsufficientAllowance)?
+        };
+        self.write_allowance(receiver, token, new_allowance);
+        self.record_event(Event::Approval {
+            owner: receiver,
+            spender: token,
+            amount: new_allowance,
+        });
+        self.burn(receiver, repayment)
+    }
+
+    /// Moves `amount` of motes from `purse` into the purse of the contract and mints the same
+    /// amount of tokens to the direct caller.
+    ///
+    /// The contract has to be installed with [`InstallOptions::wrapped`] set. Accounts deposit
+    /// through session code which funds a new purse from their main purse and passes it here.
+    pub fn deposit(&mut self, purse: URef, amount: U512) -> Result<(), Error> {
+        let owner = detail::get_immediate_caller_address()?;
+        pausable::ensure_not_paused()?;
+        let tokens = wrapped::deposit_from(purse, amount)?;
+        self.mint(owner, tokens)
+    }
+
+    /// Burns `amount` of the direct caller's tokens and sends the same amount of motes from the
+    /// purse of the contract to the caller's main purse.
+    ///
+    /// The contract has to be installed with [`InstallOptions::wrapped`] set. Only accounts can
+    /// withdraw, as contracts do not have a main purse.
+    pub fn withdraw(&mut self, amount: U256) -> Result<(), Error> {
+        let owner = detail::get_immediate_caller_address()?;
+        pausable::ensure_not_paused()?;
+        let account_hash = match owner {
+            Address::Account(account_hash) => account_hash,
+            Address::Contract(_) => return Err(Error::InvalidContext),
+        };
+        self.ensure_not_frozen(&[owner])?;
+        self.burn(owner, amount)?;
+        wrapped::withdraw_to(account_hash, amount);
+        Ok(())
+    }
+
+    /// Sets the total supply to `new_total_supply` if the direct caller has the admin role,
+    /// changing the balance of every holder in the same proportion.
+    ///
+    /// The contract has to be installed with [`InstallOptions::rebasing`] set.
+    pub fn rebase(&mut self, new_total_supply: U256) -> Result<(), Error> {
+        self.only_role(ADMIN_ROLE)?;
+        let shares = self.shares().ok_or(ApiError::MissingKey).unwrap_or_revert();
+        // Shares which are worth nothing could never be valued again, and a supply which no shares
+        // are worth would all be given to the next recipient of a mint.
+        if new_total_supply.is_zero() != shares.total_shares().is_zero() {
+            return Err(Error::InvalidRebase);
+        }
+        total_supply::ensure_within_cap(new_total_supply, self.cap())?;
+        let previous_total_supply = self.read_total_supply();
+        self.write_total_supply(new_total_supply);
+        self.record_event(Event::Rebase {
+            previous_total_supply,
+            new_total_supply,
+        });
+        Ok(())
+    }
+
+    /// Sets the transfer fee to `basis_points` of the transferred amount, but at least `minimum`,
+    /// if the direct caller has the admin role.
+    ///
+    /// The contract has to be installed with [`InstallOptions::transfer_fee`] set, and the fee can
+    /// not exceed [`MAX_TRANSFER_FEE_BASIS_POINTS`](constants::MAX_TRANSFER_FEE_BASIS_POINTS). A
+    /// minimum above that maximum is accepted, but only the maximum is ever charged.
+    pub fn set_transfer_fee(&mut self, basis_points: u16, minimum: U256) -> Result<(), Error> {
+        self.only_role(ADMIN_ROLE)?;
+        transfer_fee::write_transfer_fee(basis_points, minimum)
+    }
+
+    /// Exempts `address` from transfer fees, or revokes its exemption, if the direct caller has the
+    /// admin role.
+    ///
+    /// The contract has to be installed with [`InstallOptions::transfer_fee`] set.
+    pub fn set_fee_exempt(&mut self, address: Address, exempt: bool) -> Result<(), Error> {
+        self.only_role(ADMIN_ROLE)?;
+        transfer_fee::write_exempt(address, exempt);
         Ok(())
     }
 
-    /// Installs the ERC20 contract with a custom set of entry points.
+    /// Returns the number of addresses with a non-zero balance.
+    ///
+    /// The contract has to be installed with [`InstallOptions::holders`] set.
+    pub fn holder_count(&self) -> u64 {
+        self.holder_index()
+            .ok_or(ApiError::MissingKey)
+            .unwrap_or_revert()
+            .read_holder_count()
+    }
+
+    /// Returns at most `limit` addresses with a non-zero balance, starting with the one at
+    /// `offset`.
+    ///
+    /// Holders are listed in no particular order, and removing a holder moves the last one into
+    /// its place, so the order may change between calls. The contract has to be installed with
+    /// [`InstallOptions::holders`] set.
+    pub fn holders(&self, offset: u64, limit: u64) -> Vec<Address> {
+        self.holder_index()
+            .ok_or(ApiError::MissingKey)
+            .unwrap_or_revert()
+            .read_holders(offset, limit)
+    }
+
+    /// Returns the metadata of the token, such as the URLs of its icon and website.
+    ///
+    /// The contract has to be installed with [`InstallOptions::metadata`] set.
+    pub fn token_metadata(&self) -> BTreeMap<String, String> {
+        metadata::read_metadata()
+    }
+
+    /// Returns the version of the metadata of the token, which is incremented on every change.
+    ///
+    /// The contract has to be installed with [`InstallOptions::metadata`] set.
+    pub fn metadata_version(&self) -> u64 {
+        metadata::read_metadata_version()
+    }
+
+    /// Sets the metadata entry under `key` to `value` if the direct caller has the admin role.
+    ///
+    /// An empty `value` removes the entry. The contract has to be installed with
+    /// [`InstallOptions::metadata`] set.
+    pub fn set_metadata(&mut self, key: String, value: String) -> Result<(), Error> {
+        self.only_role(ADMIN_ROLE)?;
+        metadata::write_metadata_entry(key, value)
+    }
+
+    /// Mints `amount` new tokens to `recipient` for a transfer made on another chain, if the direct
+    /// caller has the bridge operator role.
+    ///
+    /// The transfer is identified by `source_chain_id` and `source_tx_hash`, and it is recorded so
+    /// that it can not be minted again. The contract has to be installed with
+    /// [`InstallOptions::bridge`] set.
+    pub fn mint_bridged(
+        &mut self,
+        recipient: Address,
+        amount: U256,
+        source_chain_id: u64,
+        source_tx_hash: Bytes,
+    ) -> Result<(), Error> {
+        self.only_role(BRIDGE_OPERATOR_ROLE)?;
+        pausable::ensure_not_paused()?;
+        let bridge_transfers_uref = bridge::bridge_transfers_uref();
+        if bridge::read_bridge_transfer_from(
+            bridge_transfers_uref,
+            source_chain_id,
+            &source_tx_hash,
+        )
+        .is_some()
+        {
+            return Err(Error::BridgeTransferProcessed);
+        }
+        self.mint(recipient, amount)?;
+        bridge::write_bridge_transfer_to(
+            bridge_transfers_uref,
+            source_chain_id,
+            &source_tx_hash,
+            BridgeTransfer {
+                recipient,
+                amount,
+                block_time: runtime::get_blocktime().into(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the record of the mint of a transfer made on another chain, or `None` if it has not
+    /// been minted.
+    ///
+    /// The contract has to be installed with [`InstallOptions::bridge`] set.
+    pub fn bridge_transfer(
+        &self,
+        source_chain_id: u64,
+        source_tx_hash: Bytes,
+    ) -> Option<BridgeTransfer> {
+        bridge::read_bridge_transfer_from(
+            bridge::bridge_transfers_uref(),
+            source_chain_id,
+            &source_tx_hash,
+        )
+    }
+
+    /// Burns `amount` of the direct caller's tokens to send them to `destination_address` on the
+    /// chain identified by `destination_chain_id`, and returns the index of the stored
+    /// [`BridgeRequest`].
+    ///
+    /// The amount has to be within the limits the admin has set for the destination chain. The
+    /// contract has to be installed with [`InstallOptions::bridge`] set.
+    pub fn request_bridge_back(
+        &mut self,
+        amount: U256,
+        destination_chain_id: u64,
+        destination_address: Vec<u8>,
+    ) -> Result<u64, Error> {
+        let sender = detail::get_immediate_caller_address()?;
+        pausable::ensure_not_paused()?;
+        self.ensure_not_frozen(&[sender])?;
+        bridge::ensure_within_limits(destination_chain_id, amount)?;
+        self.burn(sender, amount)?;
+        bridge::write_request(BridgeRequest {
+            sender,
+            amount,
+            destination_chain_id,
+            destination_address,
+            block_time: runtime::get_blocktime().into(),
+        })
+    }
+
+    /// Returns the bridge request stored under `index`, if there is one.
+    ///
+    /// Requests are stored under consecutive indices starting at 0, so relayers can pick them up
+    /// in order up to [`ERC20::bridge_request_count`]. The contract has to be installed with
+    /// [`InstallOptions::bridge`] set.
+    pub fn bridge_request(&self, index: u64) -> Option<BridgeRequest> {
+        bridge::read_request(index)
+    }
+
+    /// Returns the number of bridge requests made so far.
+    ///
+    /// The contract has to be installed with [`InstallOptions::bridge`] set.
+    pub fn bridge_request_count(&self) -> u64 {
+        bridge::read_request_count()
+    }
+
+    /// Sets the minimum and the maximum amount of tokens which can be sent to the chain identified
+    /// by `destination_chain_id`, if the direct caller has the admin role.
+    ///
+    /// A zero maximum stops the bridge from sending tokens to that chain. The contract has to be
+    /// installed with [`InstallOptions::bridge`] set.
+    pub fn set_bridge_limits(
+        &mut self,
+        destination_chain_id: u64,
+        minimum: U256,
+        maximum: U256,
+    ) -> Result<(), Error> {
+        self.only_role(ADMIN_ROLE)?;
+        bridge::write_limits(destination_chain_id, minimum, maximum);
+        Ok(())
+    }
+
+    /// Installs the ERC20 contract with a custom set of entry points and optional features selected
+    /// by `options`.
     ///
     /// # Warning
     ///
@@ -249,9 +1324,31 @@ impl ERC20 {
         initial_supply: U256,
         contract_key_name: &str,
         entry_points: EntryPoints,
+        options: InstallOptions,
     ) -> Result<ERC20, Error> {
+        total_supply::ensure_within_cap(initial_supply, options.cap)?;
+        if options.wrapped && decimals != WRAPPED_DECIMALS {
+            return Err(Error::InvalidDecimals);
+        }
+        if options.wrapped && !initial_supply.is_zero() {
+            return Err(Error::UnbackedSupply);
+        }
+        if let Some(transfer_fee) = &options.transfer_fee {
+            transfer_fee::ensure_within_maximum(transfer_fee.basis_points)?;
+        }
+        // Snapshots and votes record shares as they were at the time, which a rebase would make
+        // worth a different amount of tokens.
+        if options.rebasing && (options.snapshots || options.votes) {
+            return Err(Error::RebasingNotSupported);
+        }
+
         let balances_uref = storage::new_dictionary(BALANCES_KEY_NAME).unwrap_or_revert();
         let allowances_uref = storage::new_dictionary(ALLOWANCES_KEY_NAME).unwrap_or_revert();
+        let nonces_uref = storage::new_dictionary(NONCES_KEY_NAME).unwrap_or_revert();
+        let roles_uref = storage::new_dictionary(ROLES_KEY_NAME).unwrap_or_revert();
+        let frozen_uref = storage::new_dictionary(FROZEN_KEY_NAME).unwrap_or_revert();
+        let minter_allowances_uref =
+            storage::new_dictionary(MINTER_ALLOWANCES_KEY_NAME).unwrap_or_revert();
         // We need to hold on a RW access rights because tokens can be minted or burned.
         let total_supply_uref = storage::new_uref(initial_supply).into_read_write();
 
@@ -274,10 +1371,49 @@ impl ERC20 {
 
         let total_supply_key = Key::from(total_supply_uref);
 
+        let events_mode_key = {
+            let events_mode_uref = storage::new_uref(options.events_mode as u8).into_read();
+            Key::from(events_mode_uref)
+        };
+
+        let storage_version_key = {
+            let storage_version_uref = storage::new_uref(STORAGE_VERSION).into_read();
+            Key::from(storage_version_uref)
+        };
+
+        // Installer is either an account, or a contract.
+        let caller = detail::get_caller_address()?;
+
+        // Initial supply is recorded as the very first event so off-chain readers can replay
+        // every balance from the events alone.
+        let events = events::install(
+            options.events_mode,
+            Event::Mint {
+                recipient: caller,
+                amount: initial_supply,
+            },
+            &mut named_keys,
+        );
+
+        let holders = if options.holders {
+            Some(Holders::install(&mut named_keys))
+        } else {
+            None
+        };
+
+        let snapshots = if options.snapshots {
+            Some(Snapshots::install(&mut named_keys))
+        } else {
+            None
+        };
+
+        let balance_trackers = BalanceTrackers { snapshots, holders };
+
+        ownership::install(caller, &mut named_keys);
+
         let balances_dictionary_key = {
-            // Sets up initial balance for the caller - either an account, or a contract.
-            let caller = detail::get_caller_address()?;
-            balances::write_balance_to(balances_uref, caller, initial_supply);
+            // Sets up initial balance for the caller.
+            balances::write_balance_to(balances_uref, balance_trackers, caller, initial_supply);
 
             runtime::remove_key(BALANCES_KEY_NAME);
 
@@ -290,23 +1426,137 @@ impl ERC20 {
             Key::from(allowances_uref)
         };
 
+        let nonces_dictionary_key = {
+            runtime::remove_key(NONCES_KEY_NAME);
+
+            Key::from(nonces_uref)
+        };
+
+        let roles_dictionary_key = {
+            // Installer is granted all of the roles, and as an admin it can later hand them over.
+            for role in &[ADMIN_ROLE, MINTER_ROLE, BURNER_ROLE] {
+                access_control::write_role_to(roles_uref, role, caller, true);
+            }
+
+            runtime::remove_key(ROLES_KEY_NAME);
+
+            Key::from(roles_uref)
+        };
+
+        let frozen_dictionary_key = {
+            runtime::remove_key(FROZEN_KEY_NAME);
+
+            Key::from(frozen_uref)
+        };
+
+        let minter_allowances_dictionary_key = {
+            // Installer keeps minting without a limit, as it did before minters had allowances.
+            minter_allowances::write_minter_allowance_to(
+                minter_allowances_uref,
+                caller,
+                Some(U256::max_value()),
+            );
+
+            runtime::remove_key(MINTER_ALLOWANCES_KEY_NAME);
+
+            Key::from(minter_allowances_uref)
+        };
+
         named_keys.insert(NAME_KEY_NAME.to_string(), name_key);
         named_keys.insert(SYMBOL_KEY_NAME.to_string(), symbol_key);
         named_keys.insert(DECIMALS_KEY_NAME.to_string(), decimals_key);
         named_keys.insert(BALANCES_KEY_NAME.to_string(), balances_dictionary_key);
         named_keys.insert(ALLOWANCES_KEY_NAME.to_string(), allowances_dictionary_key);
+        named_keys.insert(NONCES_KEY_NAME.to_string(), nonces_dictionary_key);
+        named_keys.insert(ROLES_KEY_NAME.to_string(), roles_dictionary_key);
+        named_keys.insert(FROZEN_KEY_NAME.to_string(), frozen_dictionary_key);
+        named_keys.insert(
+            MINTER_ALLOWANCES_KEY_NAME.to_string(),
+            minter_allowances_dictionary_key,
+        );
         named_keys.insert(TOTAL_SUPPLY_KEY_NAME.to_string(), total_supply_key);
+        named_keys.insert(EVENTS_MODE_KEY_NAME.to_string(), events_mode_key);
+        named_keys.insert(STORAGE_VERSION_KEY_NAME.to_string(), storage_version_key);
 
-        let (contract_hash, _version) =
-            storage::new_locked_contract(entry_points, Some(named_keys), None, None);
+        if let Some(cap) = options.cap {
+            let cap_uref = storage::new_uref(cap).into_read();
+            named_keys.insert(CAP_KEY_NAME.to_string(), Key::from(cap_uref));
+        }
+
+        let votes = if options.votes {
+            Some(Votes::install(&mut named_keys))
+        } else {
+            None
+        };
+
+        if let Some(flash_fee_basis_points) = options.flash_fee_basis_points {
+            let flash_fee_basis_points_uref = storage::new_uref(flash_fee_basis_points).into_read();
+            named_keys.insert(
+                FLASH_FEE_BASIS_POINTS_KEY_NAME.to_string(),
+                Key::from(flash_fee_basis_points_uref),
+            );
+        }
+
+        if options.wrapped {
+            wrapped::install(&mut named_keys);
+        }
+
+        if let Some(transfer_fee) = options.transfer_fee {
+            transfer_fee::install(transfer_fee, &mut named_keys);
+        }
+
+        let total_shares_uref = if options.rebasing {
+            // Initial supply is given to the installer at one share per token.
+            let total_shares_uref = storage::new_uref(initial_supply).into_read_write();
+            named_keys.insert(
+                TOTAL_SHARES_KEY_NAME.to_string(),
+                Key::from(total_shares_uref),
+            );
+            Some(total_shares_uref)
+        } else {
+            None
+        };
+
+        if options.bridge {
+            bridge::install(&mut named_keys);
+        }
+
+        if let Some(initial_metadata) = options.metadata {
+            metadata::install(initial_metadata, &mut named_keys);
+        }
+
+        if options.pausable {
+            let paused_uref = storage::new_uref(false).into_read_write();
+            named_keys.insert(PAUSED_KEY_NAME.to_string(), Key::from(paused_uref));
+        }
+
+        let (contract_hash, _version) = match options.upgradable {
+            Some(upgrade_options) => storage::new_contract(
+                entry_points,
+                Some(named_keys),
+                Some(upgrade_options.package_hash_key_name),
+                Some(upgrade_options.access_key_name),
+            ),
+            None => storage::new_locked_contract(entry_points, Some(named_keys), None, None),
+        };
 
         // Hash of the installed contract will be reachable through named keys.
         runtime::put_key(contract_key_name, Key::from(contract_hash));
 
-        Ok(ERC20::new(
-            balances_uref,
-            allowances_uref,
-            total_supply_uref,
-        ))
+        // Named keys of the contract are not accessible to the installing session, so everything
+        // the token reads while it mints or transfers is handed over directly.
+        Ok(ERC20 {
+            balances_uref: balances_uref.into(),
+            allowances_uref: allowances_uref.into(),
+            total_supply_uref: total_supply_uref.into(),
+            nonces_uref: nonces_uref.into(),
+            frozen_uref: frozen_uref.into(),
+            total_shares_uref: total_shares_uref.into(),
+            holder_index: holders.into(),
+            events: events.into(),
+            cap: options.cap.into(),
+            snapshots: snapshots.into(),
+            votes: votes.into(),
+        })
     }
 }
@@ -0,0 +1,66 @@
+//! Implementation of mutable token metadata, such as the icon, the website and the description of
+//! the token.
+//!
+//! Metadata is a map of strings stored under the `metadata` named key. Every change increments the
+//! version stored under `metadata_version`, so that indexers can tell the metadata has changed
+//! without comparing all of it.
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+};
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{contracts::NamedKeys, Key};
+
+use crate::{
+    constants::{METADATA_KEY_NAME, METADATA_VERSION_KEY_NAME},
+    detail,
+    error::Error,
+};
+
+/// Reads the metadata of the token.
+pub(crate) fn read_metadata() -> BTreeMap<String, String> {
+    detail::read_from(METADATA_KEY_NAME)
+}
+
+/// Reads the version of the metadata, which starts at 0 and is incremented on every change.
+pub(crate) fn read_metadata_version() -> u64 {
+    detail::read_from(METADATA_VERSION_KEY_NAME)
+}
+
+/// Sets the metadata entry under `key` to `value`, or removes it if `value` is empty, and
+/// increments the version of the metadata.
+pub(crate) fn write_metadata_entry(key: String, value: String) -> Result<(), Error> {
+    let metadata_uref = detail::get_uref(METADATA_KEY_NAME);
+    let metadata_version_uref = detail::get_uref(METADATA_VERSION_KEY_NAME);
+
+    let mut metadata: BTreeMap<String, String> = storage::read(metadata_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    if value.is_empty() {
+        metadata.remove(&key);
+    } else {
+        metadata.insert(key, value);
+    }
+
+    let metadata_version: u64 = storage::read(metadata_version_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    let new_metadata_version = metadata_version.checked_add(1).ok_or(Error::Overflow)?;
+
+    storage::write(metadata_uref, metadata);
+    storage::write(metadata_version_uref, new_metadata_version);
+    Ok(())
+}
+
+/// Sets up the metadata store with the `initial` metadata, at version 0.
+pub(crate) fn install(initial: BTreeMap<String, String>, named_keys: &mut NamedKeys) {
+    let metadata_uref = storage::new_uref(initial).into_read_write();
+    let metadata_version_uref = storage::new_uref(0u64).into_read_write();
+
+    named_keys.insert(METADATA_KEY_NAME.to_string(), Key::from(metadata_uref));
+    named_keys.insert(
+        METADATA_VERSION_KEY_NAME.to_string(),
+        Key::from(metadata_version_uref),
+    );
+}
@@ -0,0 +1,42 @@
+//! Implementation of minter allowances, which limit how many tokens each minter can still mint.
+//!
+//! Every minter is limited by its allowance. The installer starts with an unlimited allowance, so
+//! that it can keep minting right after the install. Addresses which were granted the minter role
+//! directly have no allowance stored, which counts as zero, so they can not mint until they are
+//! configured with one.
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{URef, U256};
+
+use crate::{
+    constants::MINTER_ALLOWANCES_KEY_NAME,
+    detail::{self, make_dictionary_item_key},
+    Address,
+};
+
+#[inline]
+pub(crate) fn minter_allowances_uref() -> URef {
+    detail::get_uref(MINTER_ALLOWANCES_KEY_NAME)
+}
+
+/// Writes the allowance of `minter`, or `None` if it is not limited by one.
+pub(crate) fn write_minter_allowance_to(
+    minter_allowances_uref: URef,
+    minter: Address,
+    allowance: Option<U256>,
+) {
+    let dictionary_item_key = make_dictionary_item_key(minter);
+    storage::dictionary_put(minter_allowances_uref, &dictionary_item_key, allowance)
+}
+
+/// Reads the allowance of `minter`.
+///
+/// If the minter is not limited by an allowance, then `None` is returned.
+pub(crate) fn read_minter_allowance_from(
+    minter_allowances_uref: URef,
+    minter: Address,
+) -> Option<U256> {
+    let dictionary_item_key = make_dictionary_item_key(minter);
+    storage::dictionary_get(minter_allowances_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .flatten()
+}
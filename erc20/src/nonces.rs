@@ -0,0 +1,30 @@
+//! Implementation of permit nonces.
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::URef;
+
+use crate::{
+    constants::NONCES_KEY_NAME,
+    detail::{self, make_dictionary_item_key},
+    Address,
+};
+
+#[inline]
+pub(crate) fn nonces_uref() -> URef {
+    detail::get_uref(NONCES_KEY_NAME)
+}
+
+/// Writes the next permit nonce of `owner`.
+pub(crate) fn write_nonce_to(nonces_uref: URef, owner: Address, nonce: u64) {
+    let dictionary_item_key = make_dictionary_item_key(owner);
+    storage::dictionary_put(nonces_uref, &dictionary_item_key, nonce);
+}
+
+/// Reads the next permit nonce of `owner`.
+///
+/// If `owner` has never used a permit, then a 0 is returned.
+pub(crate) fn read_nonce_from(nonces_uref: URef, owner: Address) -> u64 {
+    let dictionary_item_key = make_dictionary_item_key(owner);
+    storage::dictionary_get(nonces_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
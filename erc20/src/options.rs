@@ -0,0 +1,85 @@
+//! Options which control the set of features enabled at install time.
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+use casper_types::U256;
+
+use crate::{events::EventsMode, Address};
+
+/// Optional features of the ERC20 contract which are selected once, at install time.
+///
+/// [`ERC20::install`](crate::ERC20::install) uses [`InstallOptions::default`].
+#[derive(Default, Clone, Debug)]
+pub struct InstallOptions {
+    /// Selects the way the contract records events.
+    pub events_mode: EventsMode,
+    /// Allows the admin to pause and unpause transfers and approvals.
+    pub pausable: bool,
+    /// Maximum total supply of the token, which minting can never exceed.
+    pub cap: Option<U256>,
+    /// Allows the admin to take snapshots of balances and of the total supply.
+    pub snapshots: bool,
+    /// Allows holders to delegate the voting power of their tokens.
+    pub votes: bool,
+    /// Installs the contract in an unlocked package, so that it can be upgraded later with
+    /// [`ERC20::upgrade`](crate::ERC20::upgrade). Otherwise the contract package is locked.
+    pub upgradable: Option<UpgradeOptions>,
+    /// Enables flash mints, charging a fee of the given number of basis points of the loan.
+    pub flash_fee_basis_points: Option<u16>,
+    /// Backs the token with CSPR deposited to a purse of the contract, one token per mote.
+    ///
+    /// The token has to be installed with [`WRAPPED_DECIMALS`](crate::constants::WRAPPED_DECIMALS)
+    /// decimals, and with no initial supply, as it would not be backed by any CSPR.
+    pub wrapped: bool,
+    /// Charges a fee on every transfer, which is given to a treasury.
+    pub transfer_fee: Option<TransferFeeOptions>,
+    /// Stores balances as shares of the total supply, which the admin can rebase to change every
+    /// balance at once.
+    ///
+    /// Rebasing can not be combined with [`snapshots`](InstallOptions::snapshots) or
+    /// [`votes`](InstallOptions::votes), as neither of them follows rebases.
+    pub rebasing: bool,
+    /// Keeps an index of all addresses with a non-zero balance, which can be enumerated on-chain.
+    ///
+    /// Balances given out by the installing session after
+    /// [`ERC20::install_custom`](crate::ERC20::install_custom) returns are indexed as well.
+    pub holders: bool,
+    /// Stores metadata such as the icon, the website and the description of the token, which the
+    /// admin can change later.
+    ///
+    /// Well-known entries are named by the `METADATA_*` [constants](crate::constants), such as
+    /// [`METADATA_ICON_URL`](crate::constants::METADATA_ICON_URL).
+    pub metadata: Option<BTreeMap<String, String>>,
+    /// Allows addresses with the [`BRIDGE_OPERATOR_ROLE`](crate::constants::BRIDGE_OPERATOR_ROLE)
+    /// to mint tokens for transfers bridged from other chains, each of them exactly once, and
+    /// allows holders to burn tokens to send them back to the chains the admin has set limits for.
+    pub bridge: bool,
+}
+
+/// Named keys under which the installer stores access to an upgradable contract package.
+#[derive(Clone, Debug)]
+pub struct UpgradeOptions {
+    /// Name of the installer's named key holding the hash of the contract package.
+    pub package_hash_key_name: String,
+    /// Name of the installer's named key holding the access URef of the contract package, which
+    /// is required to add new versions of the contract.
+    pub access_key_name: String,
+}
+
+/// Fee policy of a token which charges a fee on every transfer.
+///
+/// The fee is taken out of the transferred amount, so the recipient receives the amount less the
+/// fee. Admins can later change the fee up to
+/// [`MAX_TRANSFER_FEE_BASIS_POINTS`](crate::constants::MAX_TRANSFER_FEE_BASIS_POINTS).
+#[derive(Clone, Debug)]
+pub struct TransferFeeOptions {
+    /// Fee charged, in basis points of the transferred amount.
+    pub basis_points: u16,
+    /// Smallest fee charged for a transfer, unless it exceeds
+    /// [`MAX_TRANSFER_FEE_BASIS_POINTS`](crate::constants::MAX_TRANSFER_FEE_BASIS_POINTS) of the
+    /// transferred amount.
+    pub minimum: U256,
+    /// Address which receives the fees.
+    pub treasury: Address,
+    /// Addresses which pay no fees, neither when sending nor when receiving tokens.
+    pub exempt: Vec<Address>,
+}
@@ -0,0 +1,52 @@
+//! Implementation of the two-step ownership of the contract.
+//!
+//! The current owner is stored under the `owner` named key and the owner it nominated, who still
+//! has to accept the ownership, under `pending_owner`. Both are `None` once the ownership is
+//! renounced.
+//!
+//! Only transferring and renouncing the ownership are guarded by the owner itself. Every other
+//! administrative entry point is guarded by the admin role, which the owner holds: accepting the
+//! ownership moves the role from the previous owner to the new one, and renouncing the ownership
+//! revokes it from the owner, so a former owner loses all of them. Other addresses which were
+//! granted the admin role keep it until it is revoked from them.
+use alloc::string::ToString;
+
+use casper_contract::contract_api::storage;
+use casper_types::{contracts::NamedKeys, Key};
+
+use crate::{
+    constants::{OWNER_KEY_NAME, PENDING_OWNER_KEY_NAME},
+    detail, Address,
+};
+
+/// Reads the current owner of the contract.
+pub(crate) fn read_owner() -> Option<Address> {
+    detail::read_from(OWNER_KEY_NAME)
+}
+
+/// Writes the current owner of the contract.
+pub(crate) fn write_owner(owner: Option<Address>) {
+    storage::write(detail::get_uref(OWNER_KEY_NAME), owner);
+}
+
+/// Reads the owner nominated by the current one, which has not accepted the ownership yet.
+pub(crate) fn read_pending_owner() -> Option<Address> {
+    detail::read_from(PENDING_OWNER_KEY_NAME)
+}
+
+/// Writes the owner nominated by the current one.
+pub(crate) fn write_pending_owner(pending_owner: Option<Address>) {
+    storage::write(detail::get_uref(PENDING_OWNER_KEY_NAME), pending_owner);
+}
+
+/// Sets up the storage of the ownership, with `owner` as the initial owner.
+pub(crate) fn install(owner: Address, named_keys: &mut NamedKeys) {
+    let owner_uref = storage::new_uref(Some(owner)).into_read_write();
+    let pending_owner_uref = storage::new_uref(Option::<Address>::None).into_read_write();
+
+    named_keys.insert(OWNER_KEY_NAME.to_string(), Key::from(owner_uref));
+    named_keys.insert(
+        PENDING_OWNER_KEY_NAME.to_string(),
+        Key::from(pending_owner_uref),
+    );
+}
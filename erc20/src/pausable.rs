@@ -0,0 +1,29 @@
+//! Implementation of the pausable mode.
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+
+use crate::{constants::PAUSED_KEY_NAME, detail, error::Error};
+
+/// Returns `true` if the contract is pausable and it is currently paused.
+pub(crate) fn is_paused() -> bool {
+    match detail::get_optional_uref(PAUSED_KEY_NAME) {
+        Some(paused_uref) => storage::read(paused_uref)
+            .unwrap_or_revert()
+            .unwrap_or_revert(),
+        None => false,
+    }
+}
+
+/// Ensures that the contract is not paused.
+pub(crate) fn ensure_not_paused() -> Result<(), Error> {
+    if is_paused() {
+        Err(Error::Paused)
+    } else {
+        Ok(())
+    }
+}
+
+/// Sets the paused flag of a pausable contract.
+pub(crate) fn write_paused(paused: bool) {
+    let paused_uref = detail::get_uref(PAUSED_KEY_NAME);
+    storage::write(paused_uref, paused);
+}
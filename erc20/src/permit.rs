@@ -0,0 +1,61 @@
+//! Implementation of signed approvals.
+//!
+//! An owner approves a spender by signing a digest which commits to the token contract package,
+//! the spender, the amount, the owner's current nonce and a deadline. Anyone can then submit the
+//! signature to the `permit` entry point.
+use alloc::{string::String, vec::Vec};
+
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{
+    bytesrepr::{FromBytes, ToBytes},
+    crypto, ContractPackageHash, PublicKey, Signature, U256,
+};
+
+use crate::{error::Error, Address};
+
+/// Domain of the permit signatures, which distinguishes them from any other signed message.
+const PERMIT_DOMAIN: &str = "casper-erc20:permit";
+
+/// Creates a domain separator which ties permit signatures to a single token contract package.
+pub(crate) fn make_domain_separator(
+    name: &str,
+    contract_package_hash: ContractPackageHash,
+) -> [u8; 32] {
+    let mut preimage = Vec::new();
+    preimage.append(&mut String::from(PERMIT_DOMAIN).to_bytes().unwrap_or_revert());
+    preimage.append(&mut String::from(name).to_bytes().unwrap_or_revert());
+    preimage.append(&mut contract_package_hash.to_bytes().unwrap_or_revert());
+    runtime::blake2b(&preimage)
+}
+
+/// Creates a digest which has to be signed by `owner` to approve `spender` for `amount`.
+pub(crate) fn make_permit_digest(
+    domain_separator: [u8; 32],
+    owner: Address,
+    spender: Address,
+    amount: U256,
+    nonce: u64,
+    deadline: u64,
+) -> [u8; 32] {
+    let mut preimage = Vec::new();
+    preimage.extend_from_slice(&domain_separator);
+    preimage.append(&mut owner.to_bytes().unwrap_or_revert());
+    preimage.append(&mut spender.to_bytes().unwrap_or_revert());
+    preimage.append(&mut amount.to_bytes().unwrap_or_revert());
+    preimage.append(&mut nonce.to_bytes().unwrap_or_revert());
+    preimage.append(&mut deadline.to_bytes().unwrap_or_revert());
+    runtime::blake2b(&preimage)
+}
+
+/// Verifies a serialized [`Signature`] of `digest` made by `public_key`.
+pub(crate) fn verify_signature(
+    digest: [u8; 32],
+    signature: &[u8],
+    public_key: &PublicKey,
+) -> Result<(), Error> {
+    let signature = match Signature::from_bytes(signature) {
+        Ok((signature, remainder)) if remainder.is_empty() => signature,
+        _ => return Err(Error::InvalidSignature),
+    };
+    crypto::verify(&digest, &signature, public_key).map_err(|_| Error::InvalidSignature)
+}
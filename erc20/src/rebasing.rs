@@ -0,0 +1,86 @@
+//! Implementation of rebasing balances, which are stored as shares of the total supply.
+//!
+//! A holder of `shares` owns `shares * total_supply / total_shares` tokens rounded down, so that
+//! the balances of all holders never add up to more than the total supply. Amounts taken from a
+//! holder are converted into shares rounding up, and amounts given to a holder rounding down, so
+//! that no holder can ever gain from rounding at the expense of the others.
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{URef, U256, U512};
+
+use crate::{constants::TOTAL_SHARES_KEY_NAME, detail, error::Error};
+
+/// Gets the [`URef`] of the total shares, if the contract was installed with rebasing balances.
+pub(crate) fn total_shares_uref() -> Option<URef> {
+    detail::get_optional_uref(TOTAL_SHARES_KEY_NAME)
+}
+
+/// Writes the total shares to a specific [`URef`].
+pub(crate) fn write_total_shares_to(total_shares_uref: URef, total_shares: U256) {
+    storage::write(total_shares_uref, total_shares);
+}
+
+/// Computes `value * numerator / denominator` without intermediate overflow.
+fn mul_div(value: U256, numerator: U256, denominator: U256, round_up: bool) -> Result<U256, Error> {
+    let product = detail::u256_to_u512(value) * detail::u256_to_u512(numerator);
+    let denominator = detail::u256_to_u512(denominator);
+    let mut quotient = product / denominator;
+    if round_up && !(product % denominator).is_zero() {
+        quotient += U512::one();
+    }
+    detail::u512_to_u256(quotient)
+}
+
+/// Total supply and total shares of a rebasing token, which together set the value of a share.
+#[derive(Clone, Copy)]
+pub(crate) struct Shares {
+    total_supply: U256,
+    total_shares: U256,
+}
+
+impl Shares {
+    /// Reads the total shares stored under `total_shares_uref`.
+    pub(crate) fn read_from(total_shares_uref: URef, total_supply: U256) -> Self {
+        let total_shares = storage::read(total_shares_uref)
+            .unwrap_or_revert()
+            .unwrap_or_revert();
+        Shares {
+            total_supply,
+            total_shares,
+        }
+    }
+
+    /// Returns the total number of shares.
+    pub(crate) fn total_shares(&self) -> U256 {
+        self.total_shares
+    }
+
+    /// Until there are both tokens and shares, one share is worth exactly one token.
+    fn is_empty(&self) -> bool {
+        self.total_supply.is_zero() || self.total_shares.is_zero()
+    }
+
+    /// Converts `shares` into tokens, rounding down.
+    pub(crate) fn to_tokens(&self, shares: U256) -> U256 {
+        if self.is_empty() {
+            return shares;
+        }
+        // Result can not overflow, as `shares` never exceed the total shares.
+        mul_div(shares, self.total_supply, self.total_shares, false).unwrap_or_revert()
+    }
+
+    /// Converts `amount` of tokens given to a holder into shares, rounding down.
+    pub(crate) fn to_shares(&self, amount: U256) -> Result<U256, Error> {
+        if self.is_empty() {
+            return Ok(amount);
+        }
+        mul_div(amount, self.total_shares, self.total_supply, false)
+    }
+
+    /// Converts `amount` of tokens taken from a holder into shares, rounding up.
+    pub(crate) fn to_shares_rounding_up(&self, amount: U256) -> Result<U256, Error> {
+        if self.is_empty() {
+            return Ok(amount);
+        }
+        mul_div(amount, self.total_shares, self.total_supply, true)
+    }
+}
@@ -0,0 +1,119 @@
+//! Interface of contracts which receive tokens through [`ERC20::transfer_and_call`] or allowances
+//! through [`ERC20::approve_and_call`].
+//!
+//! A receiving contract exposes the [`on_erc20_received`] and [`on_erc20_approved`] entry points
+//! and returns `true` from them to accept what it was given. Returning `false` rejects the tokens,
+//! and the whole operation is reverted.
+//!
+//! [`ERC20::transfer_and_call`]: crate::ERC20::transfer_and_call
+//! [`ERC20::approve_and_call`]: crate::ERC20::approve_and_call
+use alloc::{string::String, vec};
+
+use casper_contract::contract_api::runtime;
+use casper_types::{
+    bytesrepr::Bytes, runtime_args, CLTyped, ContractPackageHash, EntryPoint, EntryPointAccess,
+    EntryPointType, Parameter, RuntimeArgs, U256,
+};
+
+use crate::{
+    constants::{
+        AMOUNT_RUNTIME_ARG_NAME, DATA_RUNTIME_ARG_NAME, ON_ERC20_APPROVED_ENTRY_POINT_NAME,
+        ON_ERC20_RECEIVED_ENTRY_POINT_NAME, OWNER_RUNTIME_ARG_NAME, SENDER_RUNTIME_ARG_NAME,
+    },
+    detail,
+    error::Error,
+    Address,
+};
+
+/// Returns the `on_erc20_received` entry point, called on the recipient after `amount` of tokens
+/// were transferred to it from `sender`.
+pub fn on_erc20_received() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ON_ERC20_RECEIVED_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(SENDER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(DATA_RUNTIME_ARG_NAME, Bytes::cl_type()),
+        ],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `on_erc20_approved` entry point, called on the spender after it was allowed to
+/// transfer up to `amount` of `owner`'s tokens.
+pub fn on_erc20_approved() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ON_ERC20_APPROVED_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(DATA_RUNTIME_ARG_NAME, Bytes::cl_type()),
+        ],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the package hash of the token contract which called the receiver's entry point.
+///
+/// Receivers should check it, as anyone can call their entry points directly.
+pub fn calling_token() -> Result<ContractPackageHash, Error> {
+    match detail::get_immediate_caller_address()? {
+        Address::Contract(contract_package_hash) => Ok(contract_package_hash),
+        Address::Account(_) => Err(Error::InvalidContext),
+    }
+}
+
+/// Calls `entry_point` on the `receiver` contract and checks whether it accepted the call.
+fn notify(receiver: Address, entry_point: &str, runtime_args: RuntimeArgs) -> Result<(), Error> {
+    let receiver_package_hash = match receiver {
+        Address::Contract(contract_package_hash) => contract_package_hash,
+        Address::Account(_) => return Err(Error::ReceiverRejected),
+    };
+    let accepted: bool =
+        runtime::call_versioned_contract(receiver_package_hash, None, entry_point, runtime_args);
+    if accepted {
+        Ok(())
+    } else {
+        Err(Error::ReceiverRejected)
+    }
+}
+
+/// Notifies `recipient` that it has received `amount` of tokens from `sender`.
+pub(crate) fn notify_received(
+    recipient: Address,
+    sender: Address,
+    amount: U256,
+    data: Bytes,
+) -> Result<(), Error> {
+    notify(
+        recipient,
+        ON_ERC20_RECEIVED_ENTRY_POINT_NAME,
+        runtime_args! {
+            SENDER_RUNTIME_ARG_NAME => sender,
+            AMOUNT_RUNTIME_ARG_NAME => amount,
+            DATA_RUNTIME_ARG_NAME => data,
+        },
+    )
+}
+
+/// Notifies `spender` that it has been allowed to spend `amount` of `owner`'s tokens.
+pub(crate) fn notify_approved(
+    spender: Address,
+    owner: Address,
+    amount: U256,
+    data: Bytes,
+) -> Result<(), Error> {
+    notify(
+        spender,
+        ON_ERC20_APPROVED_ENTRY_POINT_NAME,
+        runtime_args! {
+            OWNER_RUNTIME_ARG_NAME => owner,
+            AMOUNT_RUNTIME_ARG_NAME => amount,
+            DATA_RUNTIME_ARG_NAME => data,
+        },
+    )
+}
@@ -0,0 +1,177 @@
+//! Implementation of balance and total supply snapshots.
+//!
+//! Snapshot ids start at 1 and a new one is taken by incrementing the current id. Historical
+//! values are checkpointed lazily: right before the first write of a balance, or of the total
+//! supply, after a snapshot is taken, the value it had at that snapshot is appended to its list of
+//! checkpoints. A value which has not changed since a snapshot is simply the current value.
+use alloc::{string::ToString, vec::Vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{contracts::NamedKeys, Key, URef, U256};
+
+use crate::{
+    constants::{
+        BALANCE_SNAPSHOTS_KEY_NAME, SNAPSHOT_ID_KEY_NAME, TOTAL_SUPPLY_SNAPSHOTS_KEY_NAME,
+    },
+    detail::{self, make_dictionary_item_key},
+    error::Error,
+    Address,
+};
+
+/// Snapshot ids paired with the values they recorded, in ascending order of ids.
+type Checkpoints = Vec<(u64, U256)>;
+
+/// Appends `value` as the checkpoint of `snapshot_id`, unless it has already been checkpointed.
+///
+/// Returns `true` if the checkpoints have changed.
+fn update_checkpoints(checkpoints: &mut Checkpoints, snapshot_id: u64, value: U256) -> bool {
+    if snapshot_id == 0 {
+        return false;
+    }
+    match checkpoints.last() {
+        Some((last_snapshot_id, _)) if *last_snapshot_id >= snapshot_id => false,
+        _ => {
+            checkpoints.push((snapshot_id, value));
+            true
+        }
+    }
+}
+
+/// Returns the value recorded at `snapshot_id`, or `None` if the value has not changed since.
+fn find_checkpoint(checkpoints: &[(u64, U256)], snapshot_id: u64) -> Option<U256> {
+    let index = match checkpoints.binary_search_by_key(&snapshot_id, |(id, _)| *id) {
+        Ok(index) => index,
+        Err(index) => index,
+    };
+    checkpoints.get(index).map(|(_, value)| *value)
+}
+
+/// Storage of the snapshots.
+#[derive(Clone, Copy)]
+pub(crate) struct Snapshots {
+    snapshot_id_uref: URef,
+    balance_snapshots_uref: URef,
+    total_supply_snapshots_uref: URef,
+}
+
+impl Snapshots {
+    /// Gets the storage of the snapshots, if the contract was installed with snapshots.
+    pub(crate) fn get() -> Option<Self> {
+        let snapshot_id_uref = detail::get_optional_uref(SNAPSHOT_ID_KEY_NAME)?;
+        Some(Snapshots {
+            snapshot_id_uref,
+            balance_snapshots_uref: detail::get_uref(BALANCE_SNAPSHOTS_KEY_NAME),
+            total_supply_snapshots_uref: detail::get_uref(TOTAL_SUPPLY_SNAPSHOTS_KEY_NAME),
+        })
+    }
+
+    /// Returns the id of the last snapshot taken, or 0 if none was taken yet.
+    fn current_snapshot_id(&self) -> u64 {
+        storage::read(self.snapshot_id_uref)
+            .unwrap_or_revert()
+            .unwrap_or_revert()
+    }
+
+    /// Takes a new snapshot and returns its id.
+    pub(crate) fn snapshot(&self) -> Result<u64, Error> {
+        let new_snapshot_id = self
+            .current_snapshot_id()
+            .checked_add(1)
+            .ok_or(Error::Overflow)?;
+        storage::write(self.snapshot_id_uref, new_snapshot_id);
+        Ok(new_snapshot_id)
+    }
+
+    /// Ensures that `snapshot_id` refers to a snapshot which has already been taken.
+    fn ensure_valid_snapshot_id(&self, snapshot_id: u64) -> Result<(), Error> {
+        if snapshot_id > 0 && snapshot_id <= self.current_snapshot_id() {
+            Ok(())
+        } else {
+            Err(Error::InvalidSnapshotId)
+        }
+    }
+
+    fn read_balance_checkpoints(&self, address: Address) -> Checkpoints {
+        let dictionary_item_key = make_dictionary_item_key(address);
+        storage::dictionary_get(self.balance_snapshots_uref, &dictionary_item_key)
+            .unwrap_or_revert()
+            .unwrap_or_default()
+    }
+
+    fn read_total_supply_checkpoints(&self) -> Checkpoints {
+        storage::read(self.total_supply_snapshots_uref)
+            .unwrap_or_revert()
+            .unwrap_or_revert()
+    }
+
+    /// Records `balance` of `address` as of the current snapshot, before it gets overwritten.
+    pub(crate) fn checkpoint_balance(&self, address: Address, balance: U256) {
+        let mut checkpoints = self.read_balance_checkpoints(address);
+        if update_checkpoints(&mut checkpoints, self.current_snapshot_id(), balance) {
+            let dictionary_item_key = make_dictionary_item_key(address);
+            storage::dictionary_put(
+                self.balance_snapshots_uref,
+                &dictionary_item_key,
+                checkpoints,
+            );
+        }
+    }
+
+    /// Records `total_supply` as of the current snapshot, before it gets overwritten.
+    pub(crate) fn checkpoint_total_supply(&self, total_supply: U256) {
+        let mut checkpoints = self.read_total_supply_checkpoints();
+        if update_checkpoints(&mut checkpoints, self.current_snapshot_id(), total_supply) {
+            storage::write(self.total_supply_snapshots_uref, checkpoints);
+        }
+    }
+
+    /// Returns the balance of `address` at `snapshot_id`, or `None` if it has not changed since.
+    pub(crate) fn read_balance_at(
+        &self,
+        address: Address,
+        snapshot_id: u64,
+    ) -> Result<Option<U256>, Error> {
+        self.ensure_valid_snapshot_id(snapshot_id)?;
+        let checkpoints = self.read_balance_checkpoints(address);
+        Ok(find_checkpoint(&checkpoints, snapshot_id))
+    }
+
+    /// Returns the total supply at `snapshot_id`, or `None` if it has not changed since.
+    pub(crate) fn read_total_supply_at(&self, snapshot_id: u64) -> Result<Option<U256>, Error> {
+        self.ensure_valid_snapshot_id(snapshot_id)?;
+        let checkpoints = self.read_total_supply_checkpoints();
+        Ok(find_checkpoint(&checkpoints, snapshot_id))
+    }
+
+    /// Sets up the storage of the snapshots, with no snapshot taken yet.
+    pub(crate) fn install(named_keys: &mut NamedKeys) -> Self {
+        let snapshot_id_uref = storage::new_uref(0u64).into_read_write();
+        let balance_snapshots_uref =
+            storage::new_dictionary(BALANCE_SNAPSHOTS_KEY_NAME).unwrap_or_revert();
+        let total_supply_snapshots_uref = storage::new_uref(Checkpoints::new()).into_read_write();
+
+        runtime::remove_key(BALANCE_SNAPSHOTS_KEY_NAME);
+
+        named_keys.insert(
+            SNAPSHOT_ID_KEY_NAME.to_string(),
+            Key::from(snapshot_id_uref),
+        );
+        named_keys.insert(
+            BALANCE_SNAPSHOTS_KEY_NAME.to_string(),
+            Key::from(balance_snapshots_uref),
+        );
+        named_keys.insert(
+            TOTAL_SUPPLY_SNAPSHOTS_KEY_NAME.to_string(),
+            Key::from(total_supply_snapshots_uref),
+        );
+
+        Snapshots {
+            snapshot_id_uref,
+            balance_snapshots_uref,
+            total_supply_snapshots_uref,
+        }
+    }
+}
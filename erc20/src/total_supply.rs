@@ -3,7 +3,12 @@
 use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
 use casper_types::{URef, U256};
 
-use crate::{constants::TOTAL_SUPPLY_KEY_NAME, detail};
+use crate::{
+    constants::{CAP_KEY_NAME, TOTAL_SUPPLY_KEY_NAME},
+    detail,
+    error::Error,
+    snapshots::Snapshots,
+};
 
 #[inline]
 pub(crate) fn total_supply_uref() -> URef {
@@ -16,6 +21,28 @@ pub(crate) fn read_total_supply_from(uref: URef) -> U256 {
 }
 
 /// Writes a total supply to a specific [`URef`].
-pub(crate) fn write_total_supply_to(uref: URef, value: U256) {
+///
+/// If the contract keeps `snapshots`, the previous total supply is checkpointed first.
+pub(crate) fn write_total_supply_to(uref: URef, snapshots: Option<Snapshots>, value: U256) {
+    if let Some(snapshots) = snapshots {
+        snapshots.checkpoint_total_supply(read_total_supply_from(uref));
+    }
     storage::write(uref, value);
 }
+
+/// Reads the cap of the total supply, if the contract was installed with one.
+pub(crate) fn read_cap() -> Option<U256> {
+    detail::get_optional_uref(CAP_KEY_NAME).map(|cap_uref| {
+        storage::read(cap_uref)
+            .unwrap_or_revert()
+            .unwrap_or_revert()
+    })
+}
+
+/// Ensures that `total_supply` does not exceed the cap.
+pub(crate) fn ensure_within_cap(total_supply: U256, cap: Option<U256>) -> Result<(), Error> {
+    match cap {
+        Some(cap) if total_supply > cap => Err(Error::CapExceeded),
+        _ => Ok(()),
+    }
+}
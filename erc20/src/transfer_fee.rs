@@ -0,0 +1,131 @@
+//! Implementation of the transfer fee, which is taken out of transferred amounts and given to a
+//! treasury.
+use alloc::string::ToString;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{contracts::NamedKeys, Key, U256};
+
+use crate::{
+    constants::{
+        BASIS_POINTS, FEE_EXEMPT_KEY_NAME, MAX_TRANSFER_FEE_BASIS_POINTS, TRANSFER_FEE_KEY_NAME,
+        TREASURY_KEY_NAME,
+    },
+    detail::{self, make_dictionary_item_key},
+    error::Error,
+    options::TransferFeeOptions,
+    Address,
+};
+
+/// Part of a transferred amount which is given to the treasury.
+pub(crate) struct Fee {
+    /// Address which receives the fee.
+    pub(crate) treasury: Address,
+    /// Amount of tokens taken out of the transfer.
+    pub(crate) amount: U256,
+}
+
+/// Checks whether `address` neither pays fees when sending, nor when receiving tokens.
+fn is_exempt(address: Address) -> bool {
+    let fee_exempt_uref = detail::get_uref(FEE_EXEMPT_KEY_NAME);
+    let dictionary_item_key = make_dictionary_item_key(address);
+    storage::dictionary_get(fee_exempt_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Returns the fee charged for a transfer of `amount` from `sender` to `recipient`, if any.
+///
+/// The fee is the larger of its basis points of `amount` and its minimum, but never more than
+/// [`MAX_TRANSFER_FEE_BASIS_POINTS`] of `amount`, whatever the minimum is. Transfers to oneself,
+/// and from or to the treasury or an exempt address, are free.
+pub(crate) fn transfer_fee(
+    sender: Address,
+    recipient: Address,
+    amount: U256,
+) -> Result<Option<Fee>, Error> {
+    let transfer_fee_uref = match detail::get_optional_uref(TRANSFER_FEE_KEY_NAME) {
+        Some(transfer_fee_uref) => transfer_fee_uref,
+        None => return Ok(None),
+    };
+    if sender == recipient {
+        return Ok(None);
+    }
+    let treasury: Address = detail::read_from(TREASURY_KEY_NAME);
+    if [sender, recipient]
+        .iter()
+        .any(|address| *address == treasury || is_exempt(*address))
+    {
+        return Ok(None);
+    }
+
+    let (basis_points, minimum): (u16, U256) = storage::read(transfer_fee_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    let fee = basis_points_of(amount, basis_points)?;
+    let max_fee = basis_points_of(amount, MAX_TRANSFER_FEE_BASIS_POINTS)?;
+    let fee = fee.max(minimum).min(max_fee);
+    if fee.is_zero() {
+        return Ok(None);
+    }
+    Ok(Some(Fee {
+        treasury,
+        amount: fee,
+    }))
+}
+
+/// Returns `basis_points` of `amount`, rounded down.
+fn basis_points_of(amount: U256, basis_points: u16) -> Result<U256, Error> {
+    let product = amount
+        .checked_mul(U256::from(basis_points))
+        .ok_or(Error::Overflow)?;
+    Ok(product / U256::from(BASIS_POINTS))
+}
+
+/// Ensures that a fee of `basis_points` does not exceed the hard maximum.
+pub(crate) fn ensure_within_maximum(basis_points: u16) -> Result<(), Error> {
+    if basis_points > MAX_TRANSFER_FEE_BASIS_POINTS {
+        Err(Error::TransferFeeTooHigh)
+    } else {
+        Ok(())
+    }
+}
+
+/// Updates the basis points and the minimum of the transfer fee.
+pub(crate) fn write_transfer_fee(basis_points: u16, minimum: U256) -> Result<(), Error> {
+    ensure_within_maximum(basis_points)?;
+    let transfer_fee_uref = detail::get_uref(TRANSFER_FEE_KEY_NAME);
+    storage::write(transfer_fee_uref, (basis_points, minimum));
+    Ok(())
+}
+
+/// Exempts `address` from transfer fees, or revokes its exemption.
+pub(crate) fn write_exempt(address: Address, exempt: bool) {
+    let fee_exempt_uref = detail::get_uref(FEE_EXEMPT_KEY_NAME);
+    let dictionary_item_key = make_dictionary_item_key(address);
+    storage::dictionary_put(fee_exempt_uref, &dictionary_item_key, exempt);
+}
+
+/// Stores the fee policy selected at install time.
+pub(crate) fn install(options: TransferFeeOptions, named_keys: &mut NamedKeys) {
+    let transfer_fee_uref =
+        storage::new_uref((options.basis_points, options.minimum)).into_read_write();
+    let treasury_uref = storage::new_uref(options.treasury).into_read();
+    let fee_exempt_uref = storage::new_dictionary(FEE_EXEMPT_KEY_NAME).unwrap_or_revert();
+
+    for address in options.exempt {
+        let dictionary_item_key = make_dictionary_item_key(address);
+        storage::dictionary_put(fee_exempt_uref, &dictionary_item_key, true);
+    }
+
+    runtime::remove_key(FEE_EXEMPT_KEY_NAME);
+
+    named_keys.insert(
+        TRANSFER_FEE_KEY_NAME.to_string(),
+        Key::from(transfer_fee_uref),
+    );
+    named_keys.insert(TREASURY_KEY_NAME.to_string(), Key::from(treasury_uref));
+    named_keys.insert(FEE_EXEMPT_KEY_NAME.to_string(), Key::from(fee_exempt_uref));
+}
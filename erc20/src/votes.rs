@@ -0,0 +1,172 @@
+//! Implementation of vote delegation and checkpointed voting power.
+//!
+//! Every account may delegate the voting power of its tokens to a delegatee, possibly itself.
+//! Tokens of accounts which have not delegated do not count as votes. Voting power of each
+//! delegatee is recorded as a list of checkpoints timestamped with the block time, which allows
+//! reading the votes an account had at any moment in the past.
+use alloc::{string::ToString, vec::Vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{contracts::NamedKeys, Key, URef, U256};
+
+use crate::{
+    constants::{DELEGATES_KEY_NAME, VOTES_KEY_NAME},
+    detail::{self, make_dictionary_item_key},
+    error::Error,
+    Address,
+};
+
+/// Block times paired with the voting power since that time, in ascending order of block times.
+type Checkpoints = Vec<(u64, U256)>;
+
+fn read_delegate_from(delegates_uref: URef, account: Address) -> Option<Address> {
+    let dictionary_item_key = make_dictionary_item_key(account);
+    storage::dictionary_get(delegates_uref, &dictionary_item_key).unwrap_or_revert()
+}
+
+fn read_checkpoints_from(votes_uref: URef, account: Address) -> Checkpoints {
+    let dictionary_item_key = make_dictionary_item_key(account);
+    storage::dictionary_get(votes_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Records `votes` of `account` as of the current block time.
+fn write_checkpoint_to(votes_uref: URef, account: Address, votes: U256) {
+    let blocktime: u64 = runtime::get_blocktime().into();
+    let mut checkpoints = read_checkpoints_from(votes_uref, account);
+    match checkpoints.last_mut() {
+        // Only the final voting power within a block is of interest.
+        Some((timestamp, last_votes)) if *timestamp == blocktime => *last_votes = votes,
+        _ => checkpoints.push((blocktime, votes)),
+    }
+    let dictionary_item_key = make_dictionary_item_key(account);
+    storage::dictionary_put(votes_uref, &dictionary_item_key, checkpoints);
+}
+
+fn current_votes(checkpoints: &[(u64, U256)]) -> U256 {
+    checkpoints
+        .last()
+        .map(|(_, votes)| *votes)
+        .unwrap_or_default()
+}
+
+/// Storage of the delegates and of their voting power.
+#[derive(Clone, Copy)]
+pub(crate) struct Votes {
+    delegates_uref: URef,
+    votes_uref: URef,
+}
+
+impl Votes {
+    /// Gets the storage of the votes, if the contract was installed with votes.
+    pub(crate) fn get() -> Option<Self> {
+        let delegates_uref = detail::get_optional_uref(DELEGATES_KEY_NAME)?;
+        Some(Votes {
+            delegates_uref,
+            votes_uref: detail::get_uref(VOTES_KEY_NAME),
+        })
+    }
+
+    /// Moves voting power of `amount` tokens from the `source` delegatee to the `destination` one.
+    fn move_voting_power(
+        &self,
+        source: Option<Address>,
+        destination: Option<Address>,
+        amount: U256,
+    ) -> Result<(), Error> {
+        if source == destination || amount.is_zero() {
+            return Ok(());
+        }
+
+        if let Some(source) = source {
+            let new_votes = {
+                let votes = current_votes(&read_checkpoints_from(self.votes_uref, source));
+                votes.checked_sub(amount).ok_or(Error::Overflow)?
+            };
+            write_checkpoint_to(self.votes_uref, source, new_votes);
+        }
+
+        if let Some(destination) = destination {
+            let new_votes = {
+                let votes = current_votes(&read_checkpoints_from(self.votes_uref, destination));
+                votes.checked_add(amount).ok_or(Error::Overflow)?
+            };
+            write_checkpoint_to(self.votes_uref, destination, new_votes);
+        }
+
+        Ok(())
+    }
+
+    /// Moves voting power of `amount` tokens sent from `sender` to `recipient` between their
+    /// delegatees.
+    ///
+    /// A `sender` of `None` stands for newly minted tokens, and a `recipient` of `None` for burnt
+    /// ones.
+    pub(crate) fn move_delegate_votes(
+        &self,
+        sender: Option<Address>,
+        recipient: Option<Address>,
+        amount: U256,
+    ) -> Result<(), Error> {
+        let source = sender.and_then(|sender| self.read_delegate(sender));
+        let destination = recipient.and_then(|recipient| self.read_delegate(recipient));
+        self.move_voting_power(source, destination, amount)
+    }
+
+    /// Returns the delegatee of `account`.
+    pub(crate) fn read_delegate(&self, account: Address) -> Option<Address> {
+        read_delegate_from(self.delegates_uref, account)
+    }
+
+    /// Delegates the voting power of `delegator`, who holds `balance` tokens, to `delegatee`.
+    pub(crate) fn delegate(
+        &self,
+        delegator: Address,
+        delegatee: Address,
+        balance: U256,
+    ) -> Result<(), Error> {
+        let current_delegatee = self.read_delegate(delegator);
+
+        let dictionary_item_key = make_dictionary_item_key(delegator);
+        storage::dictionary_put(self.delegates_uref, &dictionary_item_key, delegatee);
+
+        self.move_voting_power(current_delegatee, Some(delegatee), balance)
+    }
+
+    /// Returns the current voting power of `account`.
+    pub(crate) fn read_votes(&self, account: Address) -> U256 {
+        current_votes(&read_checkpoints_from(self.votes_uref, account))
+    }
+
+    /// Returns the voting power `account` had at `blocktime`, which has to be in the past.
+    pub(crate) fn read_past_votes(&self, account: Address, blocktime: u64) -> Result<U256, Error> {
+        let current_blocktime: u64 = runtime::get_blocktime().into();
+        if blocktime >= current_blocktime {
+            return Err(Error::FutureLookup);
+        }
+        let checkpoints = read_checkpoints_from(self.votes_uref, account);
+        let index = checkpoints.partition_point(|(timestamp, _)| *timestamp <= blocktime);
+        Ok(current_votes(&checkpoints[..index]))
+    }
+
+    /// Sets up the storage of the votes, with no votes delegated yet.
+    pub(crate) fn install(named_keys: &mut NamedKeys) -> Self {
+        let delegates_uref = storage::new_dictionary(DELEGATES_KEY_NAME).unwrap_or_revert();
+        let votes_uref = storage::new_dictionary(VOTES_KEY_NAME).unwrap_or_revert();
+
+        runtime::remove_key(DELEGATES_KEY_NAME);
+        runtime::remove_key(VOTES_KEY_NAME);
+
+        named_keys.insert(DELEGATES_KEY_NAME.to_string(), Key::from(delegates_uref));
+        named_keys.insert(VOTES_KEY_NAME.to_string(), Key::from(votes_uref));
+
+        Votes {
+            delegates_uref,
+            votes_uref,
+        }
+    }
+}
@@ -0,0 +1,37 @@
+//! Implementation of the wrapped CSPR mode.
+//!
+//! Deposited CSPR is held in a purse owned by the contract, and every mote of it is backed by
+//! exactly one token, which is why a wrapped token uses the same 9 decimals as motes.
+use alloc::string::ToString;
+
+use casper_contract::{contract_api::system, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{account::AccountHash, contracts::NamedKeys, Key, URef, U256, U512};
+
+use crate::{constants::WRAPPED_PURSE_KEY_NAME, detail, error::Error};
+
+/// Moves `amount` of motes from `source` into the purse of the contract and returns the amount of
+/// tokens which they back.
+pub(crate) fn deposit_from(source: URef, amount: U512) -> Result<U256, Error> {
+    let tokens = detail::u512_to_u256(amount)?;
+    let wrapped_purse = detail::get_uref(WRAPPED_PURSE_KEY_NAME);
+    system::transfer_from_purse_to_purse(source, wrapped_purse, amount, None).unwrap_or_revert();
+    Ok(tokens)
+}
+
+/// Sends the motes backing `amount` of tokens from the purse of the contract to `account_hash`.
+pub(crate) fn withdraw_to(account_hash: AccountHash, amount: U256) {
+    let wrapped_purse = detail::get_uref(WRAPPED_PURSE_KEY_NAME);
+    system::transfer_from_purse_to_account(
+        wrapped_purse,
+        account_hash,
+        detail::u256_to_u512(amount),
+        None,
+    )
+    .unwrap_or_revert();
+}
+
+/// Creates the purse which holds the deposited CSPR.
+pub(crate) fn install(named_keys: &mut NamedKeys) {
+    let wrapped_purse = system::create_purse();
+    named_keys.insert(WRAPPED_PURSE_KEY_NAME.to_string(), Key::from(wrapped_purse));
+}
@@ -6,18 +6,19 @@ compile_error!("target arch should be wasm32: compile with '--target wasm32-unkn
 
 extern crate alloc;
 
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 
 use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
 use casper_erc20::{
     constants::{
-        ADDRESS_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME, DECIMALS_RUNTIME_ARG_NAME,
-        NAME_RUNTIME_ARG_NAME, OWNER_RUNTIME_ARG_NAME, RECIPIENT_RUNTIME_ARG_NAME,
+        ADDRESS_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME, DEADLINE_RUNTIME_ARG_NAME,
+        DECIMALS_RUNTIME_ARG_NAME, NAME_RUNTIME_ARG_NAME, NONCE_RUNTIME_ARG_NAME,
+        OWNER_RUNTIME_ARG_NAME, RECIPIENT_RUNTIME_ARG_NAME, SIGNATURE_RUNTIME_ARG_NAME,
         SPENDER_RUNTIME_ARG_NAME, SYMBOL_RUNTIME_ARG_NAME, TOTAL_SUPPLY_RUNTIME_ARG_NAME,
     },
     Address, ERC20,
 };
-use casper_types::{CLValue, U256};
+use casper_types::{CLValue, PublicKey, U256};
 
 #[no_mangle]
 pub extern "C" fn name() {
@@ -68,6 +69,26 @@ pub extern "C" fn approve() {
     ERC20::default().approve(spender, amount).unwrap_or_revert();
 }
 
+#[no_mangle]
+pub extern "C" fn increase_allowance() {
+    let spender: Address = runtime::get_named_arg(SPENDER_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    ERC20::default()
+        .increase_allowance(spender, amount)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn decrease_allowance() {
+    let spender: Address = runtime::get_named_arg(SPENDER_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    ERC20::default()
+        .decrease_allowance(spender, amount)
+        .unwrap_or_revert();
+}
+
 #[no_mangle]
 pub extern "C" fn allowance() {
     let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
@@ -86,6 +107,38 @@ pub extern "C" fn transfer_from() {
         .unwrap_or_revert();
 }
 
+#[no_mangle]
+pub extern "C" fn permit() {
+    let owner: PublicKey = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let spender: Address = runtime::get_named_arg(SPENDER_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let deadline: u64 = runtime::get_named_arg(DEADLINE_RUNTIME_ARG_NAME);
+    let nonce: u64 = runtime::get_named_arg(NONCE_RUNTIME_ARG_NAME);
+    let signature: Vec<u8> = runtime::get_named_arg(SIGNATURE_RUNTIME_ARG_NAME);
+    ERC20::default()
+        .permit(owner, spender, amount, deadline, nonce, signature)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn nonces() {
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let nonce = ERC20::default().nonces(owner);
+    runtime::ret(CLValue::from_t(nonce).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn domain_separator() {
+    let domain_separator = ERC20::default().domain_separator().unwrap_or_revert();
+    runtime::ret(CLValue::from_t(domain_separator).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn storage_version() {
+    let storage_version = ERC20::default().storage_version();
+    runtime::ret(CLValue::from_t(storage_version).unwrap_or_revert());
+}
+
 #[no_mangle]
 fn call() {
     let name: String = runtime::get_named_arg(NAME_RUNTIME_ARG_NAME);
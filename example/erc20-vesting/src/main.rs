@@ -0,0 +1,363 @@
+//! Vesting contract which holds tokens of a `casper-erc20` token and releases them to
+//! beneficiaries linearly over time, after a cliff.
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::convert::TryInto;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_erc20::{
+    constants::{
+        AMOUNT_RUNTIME_ARG_NAME, OWNER_RUNTIME_ARG_NAME, RECIPIENT_RUNTIME_ARG_NAME,
+        TRANSFER_ENTRY_POINT_NAME, TRANSFER_FROM_ENTRY_POINT_NAME,
+    },
+    Address, Error,
+};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    contracts::NamedKeys,
+    runtime_args,
+    system::CallStackElement,
+    CLType, CLTyped, CLValue, ContractHash, EntryPoint, EntryPointAccess, EntryPointType,
+    EntryPoints, Key, Parameter, RuntimeArgs, URef, U256,
+};
+
+const VESTING_CONTRACT_KEY_NAME: &str = "erc20_vesting_contract";
+const VESTING_CONTRACT_PACKAGE_KEY_NAME: &str = "erc20_vesting_contract_package";
+const TOKEN_CONTRACT_KEY_NAME: &str = "token_contract";
+const OWNER_KEY_NAME: &str = "owner";
+const SCHEDULES_KEY_NAME: &str = "schedules";
+
+const CREATE_SCHEDULE_ENTRY_POINT_NAME: &str = "create_schedule";
+const RELEASE_ENTRY_POINT_NAME: &str = "release";
+const RELEASABLE_ENTRY_POINT_NAME: &str = "releasable";
+
+const TOKEN_CONTRACT_RUNTIME_ARG_NAME: &str = "token_contract";
+const BENEFICIARY_RUNTIME_ARG_NAME: &str = "beneficiary";
+const TOTAL_RUNTIME_ARG_NAME: &str = "total";
+const START_RUNTIME_ARG_NAME: &str = "start";
+const CLIFF_RUNTIME_ARG_NAME: &str = "cliff";
+const DURATION_RUNTIME_ARG_NAME: &str = "duration";
+
+/// Caller is not the owner of the vesting contract.
+const ERROR_NOT_OWNER: u16 = 1;
+/// Beneficiary already has a vesting schedule.
+const ERROR_SCHEDULE_EXISTS: u16 = 2;
+/// Vesting schedule has no tokens or no duration, or its cliff is longer than its duration.
+const ERROR_INVALID_SCHEDULE: u16 = 3;
+/// Caller does not have a vesting schedule.
+const ERROR_NO_SCHEDULE: u16 = 4;
+/// None of the caller's tokens can be released yet.
+const ERROR_NOTHING_TO_RELEASE: u16 = 5;
+
+/// Tokens of a single beneficiary, vesting linearly from `start` over `duration` milliseconds,
+/// none of which can be released during the first `cliff` milliseconds.
+///
+/// Stored as a `((U256, U256), (u64, u64), u64)` tuple of the total and released amounts, the start
+/// and the cliff, and the duration.
+struct Schedule {
+    total: U256,
+    released: U256,
+    start: u64,
+    cliff: u64,
+    duration: u64,
+}
+
+impl Schedule {
+    /// Returns the amount of tokens vested at `blocktime`, including the released ones.
+    fn vested_at(&self, blocktime: u64) -> U256 {
+        let elapsed = blocktime.saturating_sub(self.start);
+        if elapsed < self.cliff {
+            U256::zero()
+        } else if elapsed >= self.duration {
+            self.total
+        } else {
+            self.total
+                .checked_mul(U256::from(elapsed))
+                .ok_or(Error::Overflow)
+                .unwrap_or_revert()
+                / U256::from(self.duration)
+        }
+    }
+
+    /// Returns the amount of tokens which can be released at `blocktime`.
+    fn releasable_at(&self, blocktime: u64) -> U256 {
+        self.vested_at(blocktime) - self.released
+    }
+}
+
+impl CLTyped for Schedule {
+    fn cl_type() -> CLType {
+        // Tuples have at most three elements, so the fields are nested in pairs. Nesting does not
+        // change the serialization, which is the fields one after another.
+        <((U256, U256), (u64, u64), u64)>::cl_type()
+    }
+}
+
+impl ToBytes for Schedule {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.total.to_bytes()?);
+        result.append(&mut self.released.to_bytes()?);
+        result.append(&mut self.start.to_bytes()?);
+        result.append(&mut self.cliff.to_bytes()?);
+        result.append(&mut self.duration.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.total.serialized_length()
+            + self.released.serialized_length()
+            + self.start.serialized_length()
+            + self.cliff.serialized_length()
+            + self.duration.serialized_length()
+    }
+}
+
+impl FromBytes for Schedule {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (total, bytes) = U256::from_bytes(bytes)?;
+        let (released, bytes) = U256::from_bytes(bytes)?;
+        let (start, bytes) = u64::from_bytes(bytes)?;
+        let (cliff, bytes) = u64::from_bytes(bytes)?;
+        let (duration, bytes) = u64::from_bytes(bytes)?;
+        let schedule = Schedule {
+            total,
+            released,
+            start,
+            cliff,
+            duration,
+        };
+        Ok((schedule, bytes))
+    }
+}
+
+fn get_uref(name: &str) -> URef {
+    runtime::get_key(name)
+        .unwrap_or_revert()
+        .try_into()
+        .unwrap_or_revert()
+}
+
+fn token_contract() -> ContractHash {
+    runtime::get_key(TOKEN_CONTRACT_KEY_NAME)
+        .and_then(Key::into_hash)
+        .map(ContractHash::new)
+        .unwrap_or_revert()
+}
+
+/// Creates a dictionary item key for the schedule of `beneficiary`.
+fn schedule_key(beneficiary: Address) -> String {
+    base64::encode(&beneficiary.to_bytes().unwrap_or_revert())
+}
+
+fn read_schedule(beneficiary: Address) -> Option<Schedule> {
+    storage::dictionary_get(get_uref(SCHEDULES_KEY_NAME), &schedule_key(beneficiary))
+        .unwrap_or_revert()
+}
+
+fn write_schedule(beneficiary: Address, schedule: Schedule) {
+    storage::dictionary_put(
+        get_uref(SCHEDULES_KEY_NAME),
+        &schedule_key(beneficiary),
+        schedule,
+    );
+}
+
+fn current_blocktime() -> u64 {
+    runtime::get_blocktime().into()
+}
+
+fn call_stack_element_to_address(call_stack_element: CallStackElement) -> Address {
+    match call_stack_element {
+        CallStackElement::Session { account_hash }
+        | CallStackElement::StoredSession { account_hash, .. } => Address::from(account_hash),
+        CallStackElement::StoredContract {
+            contract_package_hash,
+            ..
+        } => Address::from(contract_package_hash),
+    }
+}
+
+/// Returns the address of the direct caller of the vesting contract.
+fn get_immediate_caller_address() -> Address {
+    runtime::get_call_stack()
+        .into_iter()
+        .rev()
+        .nth(1)
+        .map(call_stack_element_to_address)
+        .ok_or(Error::InvalidContext)
+        .unwrap_or_revert()
+}
+
+/// Returns the address of the vesting contract itself, which holds the vesting tokens.
+fn get_self_address() -> Address {
+    runtime::get_call_stack()
+        .into_iter()
+        .rev()
+        .next()
+        .map(call_stack_element_to_address)
+        .ok_or(Error::InvalidContext)
+        .unwrap_or_revert()
+}
+
+/// Creates a vesting schedule of `total` tokens for `beneficiary`, taking the tokens from the
+/// owner of the vesting contract.
+///
+/// The owner has to approve the vesting contract to spend `total` of its tokens first.
+#[no_mangle]
+pub extern "C" fn create_schedule() {
+    let owner: Address = storage::read(get_uref(OWNER_KEY_NAME))
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    if get_immediate_caller_address() != owner {
+        runtime::revert(Error::User(ERROR_NOT_OWNER));
+    }
+
+    let beneficiary: Address = runtime::get_named_arg(BENEFICIARY_RUNTIME_ARG_NAME);
+    let total: U256 = runtime::get_named_arg(TOTAL_RUNTIME_ARG_NAME);
+    let start: u64 = runtime::get_named_arg(START_RUNTIME_ARG_NAME);
+    let cliff: u64 = runtime::get_named_arg(CLIFF_RUNTIME_ARG_NAME);
+    let duration: u64 = runtime::get_named_arg(DURATION_RUNTIME_ARG_NAME);
+
+    if read_schedule(beneficiary).is_some() {
+        runtime::revert(Error::User(ERROR_SCHEDULE_EXISTS));
+    }
+    if total.is_zero() || duration == 0 || cliff > duration {
+        runtime::revert(Error::User(ERROR_INVALID_SCHEDULE));
+    }
+
+    write_schedule(
+        beneficiary,
+        Schedule {
+            total,
+            released: U256::zero(),
+            start,
+            cliff,
+            duration,
+        },
+    );
+
+    runtime::call_contract::<()>(
+        token_contract(),
+        TRANSFER_FROM_ENTRY_POINT_NAME,
+        runtime_args! {
+            OWNER_RUNTIME_ARG_NAME => owner,
+            RECIPIENT_RUNTIME_ARG_NAME => get_self_address(),
+            AMOUNT_RUNTIME_ARG_NAME => total,
+        },
+    );
+}
+
+/// Sends all of the caller's vested tokens which have not been released yet.
+#[no_mangle]
+pub extern "C" fn release() {
+    let beneficiary = get_immediate_caller_address();
+    let mut schedule = read_schedule(beneficiary)
+        .ok_or(Error::User(ERROR_NO_SCHEDULE))
+        .unwrap_or_revert();
+
+    let amount = schedule.releasable_at(current_blocktime());
+    if amount.is_zero() {
+        runtime::revert(Error::User(ERROR_NOTHING_TO_RELEASE));
+    }
+    schedule.released += amount;
+    write_schedule(beneficiary, schedule);
+
+    runtime::call_contract::<()>(
+        token_contract(),
+        TRANSFER_ENTRY_POINT_NAME,
+        runtime_args! {
+            RECIPIENT_RUNTIME_ARG_NAME => beneficiary,
+            AMOUNT_RUNTIME_ARG_NAME => amount,
+        },
+    );
+}
+
+/// Returns the amount of `beneficiary`'s tokens which can be released now.
+#[no_mangle]
+pub extern "C" fn releasable() {
+    let beneficiary: Address = runtime::get_named_arg(BENEFICIARY_RUNTIME_ARG_NAME);
+    let releasable = read_schedule(beneficiary)
+        .map(|schedule| schedule.releasable_at(current_blocktime()))
+        .unwrap_or_default();
+    runtime::ret(CLValue::from_t(releasable).unwrap_or_revert());
+}
+
+fn entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(CREATE_SCHEDULE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(BENEFICIARY_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(TOTAL_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(START_RUNTIME_ARG_NAME, u64::cl_type()),
+            Parameter::new(CLIFF_RUNTIME_ARG_NAME, u64::cl_type()),
+            Parameter::new(DURATION_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(RELEASE_ENTRY_POINT_NAME),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(RELEASABLE_ENTRY_POINT_NAME),
+        vec![Parameter::new(
+            BENEFICIARY_RUNTIME_ARG_NAME,
+            Address::cl_type(),
+        )],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points
+}
+
+#[no_mangle]
+fn call() {
+    let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
+
+    // Installer becomes the owner, which is the only one allowed to create schedules.
+    let owner = Address::from(runtime::get_caller());
+
+    let schedules_uref = storage::new_dictionary(SCHEDULES_KEY_NAME).unwrap_or_revert();
+    runtime::remove_key(SCHEDULES_KEY_NAME);
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(
+        TOKEN_CONTRACT_KEY_NAME.to_string(),
+        Key::from(token_contract),
+    );
+    named_keys.insert(
+        OWNER_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(owner).into_read()),
+    );
+    named_keys.insert(SCHEDULES_KEY_NAME.to_string(), Key::from(schedules_uref));
+
+    let (contract_hash, _version) = storage::new_locked_contract(
+        entry_points(),
+        Some(named_keys),
+        Some(VESTING_CONTRACT_PACKAGE_KEY_NAME.to_string()),
+        None,
+    );
+    runtime::put_key(VESTING_CONTRACT_KEY_NAME, Key::from(contract_hash));
+}
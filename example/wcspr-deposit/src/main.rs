@@ -0,0 +1,41 @@
+//! Session code which deposits CSPR from the main purse of the calling account to a wrapped CSPR
+//! token built with `casper-erc20`.
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+use casper_contract::{
+    contract_api::{account, runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_erc20::constants::{
+    AMOUNT_RUNTIME_ARG_NAME, DEPOSIT_ENTRY_POINT_NAME, PURSE_RUNTIME_ARG_NAME,
+};
+use casper_types::{runtime_args, ContractHash, RuntimeArgs, U512};
+
+const TOKEN_CONTRACT_RUNTIME_ARG_NAME: &str = "token_contract";
+
+/// Deposits `amount` of motes from the main purse of the calling account to a wrapped CSPR token.
+///
+/// The motes are moved to a new purse first, so that the token contract never gets access to the
+/// main purse of the account.
+#[no_mangle]
+pub extern "C" fn call() {
+    let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
+    let amount: U512 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    let purse = system::create_purse();
+    system::transfer_from_purse_to_purse(account::get_main_purse(), purse, amount, None)
+        .unwrap_or_revert();
+
+    runtime::call_contract::<()>(
+        token_contract,
+        DEPOSIT_ENTRY_POINT_NAME,
+        runtime_args! {
+            PURSE_RUNTIME_ARG_NAME => purse,
+            AMOUNT_RUNTIME_ARG_NAME => amount,
+        },
+    );
+}
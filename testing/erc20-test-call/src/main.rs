@@ -6,11 +6,13 @@ extern crate alloc;
 use alloc::{
     string::{String, ToString},
     vec,
+    vec::Vec,
 };
 
 use casper_contract::{
     self,
     contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_erc20::{
     constants::{
@@ -20,8 +22,9 @@ use casper_erc20::{
     Address,
 };
 use casper_types::{
-    bytesrepr::ToBytes, runtime_args, CLTyped, ContractHash, EntryPoint, EntryPointAccess,
-    EntryPointType, EntryPoints, Key, Parameter, RuntimeArgs, U256,
+    bytesrepr::{Bytes, ToBytes},
+    runtime_args, CLTyped, CLValue, ContractHash, EntryPoint, EntryPointAccess, EntryPointType,
+    EntryPoints, Key, Parameter, RuntimeArgs, U256,
 };
 
 const CHECK_TOTAL_SUPPLY_ENTRY_POINT_NAME: &str = "check_total_supply";
@@ -30,10 +33,23 @@ const TRANSFER_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str = "transfer_as_stored_c
 const APPROVE_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str = "approve_as_stored_contract";
 const TRANSFER_FROM_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str = "transfer_from_as_stored_contract";
 const CHECK_ALLOWANCE_OF_ENTRY_POINT_NAME: &str = "check_allowance_of";
+const CHECK_BALANCE_OF_AT_ENTRY_POINT_NAME: &str = "check_balance_of_at";
+const CHECK_TOTAL_SUPPLY_AT_ENTRY_POINT_NAME: &str = "check_total_supply_at";
+const CHECK_HOLDER_COUNT_ENTRY_POINT_NAME: &str = "check_holder_count";
+const CHECK_HOLDERS_ENTRY_POINT_NAME: &str = "check_holders";
 const TOKEN_CONTRACT_RUNTIME_ARG_NAME: &str = "token_contract";
 const ADDRESS_RUNTIME_ARG_NAME: &str = "address";
 const OWNER_RUNTIME_ARG_NAME: &str = "owner";
 const SPENDER_RUNTIME_ARG_NAME: &str = "spender";
+const SNAPSHOT_ID_RUNTIME_ARG_NAME: &str = "snapshot_id";
+const OFFSET_RUNTIME_ARG_NAME: &str = "offset";
+const LIMIT_RUNTIME_ARG_NAME: &str = "limit";
+const SENDER_RUNTIME_ARG_NAME: &str = "sender";
+const DATA_RUNTIME_ARG_NAME: &str = "data";
+const INITIATOR_RUNTIME_ARG_NAME: &str = "initiator";
+const FEE_RUNTIME_ARG_NAME: &str = "fee";
+const REJECT_DATA: &[u8] = b"reject";
+const KEEP_DATA: &[u8] = b"keep";
 const RESULT_KEY: &str = "result";
 const ERC20_TEST_CALL_KEY: &str = "erc20_test_call";
 
@@ -95,6 +111,127 @@ extern "C" fn check_allowance_of() {
     store_result(result);
 }
 
+#[no_mangle]
+extern "C" fn check_balance_of_at() {
+    let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let snapshot_id: u64 = runtime::get_named_arg(SNAPSHOT_ID_RUNTIME_ARG_NAME);
+
+    let balance_args = runtime_args! {
+        casper_erc20::constants::ADDRESS_RUNTIME_ARG_NAME => address,
+        casper_erc20::constants::SNAPSHOT_ID_RUNTIME_ARG_NAME => snapshot_id,
+    };
+    let result: U256 = runtime::call_contract(
+        token_contract,
+        casper_erc20::constants::BALANCE_OF_AT_ENTRY_POINT_NAME,
+        balance_args,
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_total_supply_at() {
+    let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
+    let snapshot_id: u64 = runtime::get_named_arg(SNAPSHOT_ID_RUNTIME_ARG_NAME);
+
+    let total_supply_args = runtime_args! {
+        casper_erc20::constants::SNAPSHOT_ID_RUNTIME_ARG_NAME => snapshot_id,
+    };
+    let result: U256 = runtime::call_contract(
+        token_contract,
+        casper_erc20::constants::TOTAL_SUPPLY_AT_ENTRY_POINT_NAME,
+        total_supply_args,
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_holder_count() {
+    let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
+    let holder_count: u64 = runtime::call_contract(
+        token_contract,
+        casper_erc20::constants::HOLDER_COUNT_ENTRY_POINT_NAME,
+        RuntimeArgs::default(),
+    );
+    store_result(holder_count);
+}
+
+#[no_mangle]
+extern "C" fn check_holders() {
+    let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
+    let offset: u64 = runtime::get_named_arg(OFFSET_RUNTIME_ARG_NAME);
+    let limit: u64 = runtime::get_named_arg(LIMIT_RUNTIME_ARG_NAME);
+
+    let holders_args = runtime_args! {
+        casper_erc20::constants::OFFSET_RUNTIME_ARG_NAME => offset,
+        casper_erc20::constants::LIMIT_RUNTIME_ARG_NAME => limit,
+    };
+    let result: Vec<Address> = runtime::call_contract(
+        token_contract,
+        casper_erc20::constants::HOLDERS_ENTRY_POINT_NAME,
+        holders_args,
+    );
+
+    store_result(result);
+}
+
+/// Accepts tokens unless asked to reject them, and records who sent how much.
+#[no_mangle]
+extern "C" fn on_erc20_received() {
+    casper_erc20::receiver::calling_token().unwrap_or_revert();
+    let sender: Address = runtime::get_named_arg(SENDER_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let data: Bytes = runtime::get_named_arg(DATA_RUNTIME_ARG_NAME);
+
+    store_result((sender, amount));
+
+    let accepted = &data[..] != REJECT_DATA;
+    runtime::ret(CLValue::from_t(accepted).unwrap_or_revert());
+}
+
+/// Accepts allowances unless asked to reject them, and records who approved how much.
+#[no_mangle]
+extern "C" fn on_erc20_approved() {
+    casper_erc20::receiver::calling_token().unwrap_or_revert();
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let data: Bytes = runtime::get_named_arg(DATA_RUNTIME_ARG_NAME);
+
+    store_result((owner, amount));
+
+    let accepted = &data[..] != REJECT_DATA;
+    runtime::ret(CLValue::from_t(accepted).unwrap_or_revert());
+}
+
+/// Repays flash loans unless asked to reject them or to keep the tokens, and records who
+/// initiated a loan of how much for what fee.
+#[no_mangle]
+extern "C" fn on_flash_loan() {
+    let token = casper_erc20::receiver::calling_token().unwrap_or_revert();
+    let initiator: Address = runtime::get_named_arg(INITIATOR_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let fee: U256 = runtime::get_named_arg(FEE_RUNTIME_ARG_NAME);
+    let data: Bytes = runtime::get_named_arg(DATA_RUNTIME_ARG_NAME);
+
+    store_result((initiator, amount, fee));
+
+    if &data[..] == REJECT_DATA {
+        runtime::ret(CLValue::from_t(false).unwrap_or_revert());
+    }
+
+    if &data[..] != KEEP_DATA {
+        let approve_args = runtime_args! {
+            SPENDER_RUNTIME_ARG_NAME => Address::from(token),
+            AMOUNT_RUNTIME_ARG_NAME => amount + fee,
+        };
+        runtime::call_versioned_contract::<()>(token, None, APPROVE_ENTRY_POINT_NAME, approve_args);
+    }
+
+    runtime::ret(CLValue::from_t(true).unwrap_or_revert());
+}
+
 #[no_mangle]
 extern "C" fn transfer_as_stored_contract() {
     let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
@@ -177,6 +314,48 @@ pub extern "C" fn call() {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     );
+    let check_balance_of_at_entrypoint = EntryPoint::new(
+        String::from(CHECK_BALANCE_OF_AT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(SNAPSHOT_ID_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_total_supply_at_entrypoint = EntryPoint::new(
+        String::from(CHECK_TOTAL_SUPPLY_AT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(SNAPSHOT_ID_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_holder_count_entrypoint = EntryPoint::new(
+        String::from(CHECK_HOLDER_COUNT_ENTRY_POINT_NAME),
+        vec![Parameter::new(
+            TOKEN_CONTRACT_RUNTIME_ARG_NAME,
+            ContractHash::cl_type(),
+        )],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_holders_entrypoint = EntryPoint::new(
+        String::from(CHECK_HOLDERS_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(OFFSET_RUNTIME_ARG_NAME, u64::cl_type()),
+            Parameter::new(LIMIT_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
 
     let transfer_as_stored_contract_entrypoint = EntryPoint::new(
         String::from(TRANSFER_AS_STORED_CONTRACT_ENTRY_POINT_NAME),
@@ -218,7 +397,14 @@ pub extern "C" fn call() {
     entry_points.add_entry_point(check_total_supply_entrypoint);
     entry_points.add_entry_point(check_balance_of_entrypoint);
     entry_points.add_entry_point(check_allowance_of_entrypoint);
+    entry_points.add_entry_point(check_balance_of_at_entrypoint);
+    entry_points.add_entry_point(check_total_supply_at_entrypoint);
+    entry_points.add_entry_point(check_holder_count_entrypoint);
+    entry_points.add_entry_point(check_holders_entrypoint);
     entry_points.add_entry_point(transfer_as_stored_contract_entrypoint);
+    entry_points.add_entry_point(casper_erc20::receiver::on_erc20_received());
+    entry_points.add_entry_point(casper_erc20::receiver::on_erc20_approved());
+    entry_points.add_entry_point(casper_erc20::flash_mint::on_flash_loan());
     entry_points.add_entry_point(approve_as_stored_contract_entrypoint);
     entry_points.add_entry_point(transfer_from_as_stored_contract_entrypoint);
 
@@ -4,36 +4,58 @@
 extern crate alloc;
 
 use alloc::{
+    collections::BTreeMap,
     string::{String, ToString},
-    vec,
+    vec::Vec,
+};
+use core::{
+    convert::TryFrom,
+    ops::{Deref, DerefMut},
 };
-use core::ops::{Deref, DerefMut};
 
 use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
 use casper_erc20::{
-    constants::{ADDRESS_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME, OWNER_RUNTIME_ARG_NAME},
-    Address, Error, ERC20,
+    constants::{
+        ACCOUNT_RUNTIME_ARG_NAME, ADDRESS_RUNTIME_ARG_NAME, ALLOWANCE_RUNTIME_ARG_NAME,
+        AMOUNTS_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME, BASIS_POINTS_RUNTIME_ARG_NAME,
+        BLOCK_TIME_RUNTIME_ARG_NAME, DATA_RUNTIME_ARG_NAME, DELEGATEE_RUNTIME_ARG_NAME,
+        DESTINATION_ADDRESS_RUNTIME_ARG_NAME, DESTINATION_CHAIN_ID_RUNTIME_ARG_NAME,
+        EVENTS_MODE_RUNTIME_ARG_NAME, EXEMPT_RUNTIME_ARG_NAME, EXPIRES_AT_RUNTIME_ARG_NAME,
+        INDEX_RUNTIME_ARG_NAME, KEY_RUNTIME_ARG_NAME, LIMIT_RUNTIME_ARG_NAME,
+        MAXIMUM_RUNTIME_ARG_NAME, METADATA_ICON_URL, MINIMUM_RUNTIME_ARG_NAME,
+        MINTER_RUNTIME_ARG_NAME, NEW_OWNER_RUNTIME_ARG_NAME, OFFSET_RUNTIME_ARG_NAME,
+        OWNER_RUNTIME_ARG_NAME, RECEIVER_RUNTIME_ARG_NAME, RECIPIENTS_RUNTIME_ARG_NAME,
+        RECIPIENT_RUNTIME_ARG_NAME, ROLE_RUNTIME_ARG_NAME, SNAPSHOT_ID_RUNTIME_ARG_NAME,
+        SOURCE_CHAIN_ID_RUNTIME_ARG_NAME, SOURCE_TX_HASH_RUNTIME_ARG_NAME,
+        SPENDER_RUNTIME_ARG_NAME, STORAGE_VERSION_KEY_NAME, VALUE_RUNTIME_ARG_NAME,
+    },
+    Address, Error, EventsMode, InstallOptions, TransferFeeOptions, UpgradeOptions, ERC20,
 };
 use casper_types::{
-    account::AccountHash, CLType, CLTyped, CLValue, ContractPackageHash, EntryPoint,
-    EntryPointAccess, EntryPointType, EntryPoints, Parameter, U256,
+    account::AccountHash, bytesrepr::Bytes, CLType, CLValue, ContractPackageHash, EntryPoint,
+    EntryPointAccess, EntryPointType, EntryPoints, U256,
 };
 
-const MINT_ENTRY_POINT_NAME: &str = "mint";
-const BURN_ENTRY_POINT_NAME: &str = "burn";
-
 /// "erc20" is not mentioned here intentionally as the functionality is not compatible with ERC20
 /// token standard.
 const TEST_CONTRACT_KEY_NAME: &str = "test_contract";
+const TEST_CONTRACT_PACKAGE_KEY_NAME: &str = "test_contract_package";
+const TEST_CONTRACT_ACCESS_KEY_NAME: &str = "test_contract_access";
+const UPGRADE_RUNTIME_ARG_NAME: &str = "upgrade";
+const TOKEN_OWNER_AMOUNT_2_RUNTIME_ARG_NAME: &str = "token_owner_amount_2";
+const FORGET_STORAGE_VERSION_ENTRY_POINT_NAME: &str = "forget_storage_version";
 const TOKEN_NAME: &str = "CasperTest";
 const TOKEN_SYMBOL: &str = "CSPRT";
 const TOKEN_DECIMALS: u8 = 8;
 const TOKEN_TOTAL_SUPPLY: u64 = 1_000_000_000;
+const TOKEN_CAP: u64 = 2_000_000_000;
+const TOKEN_FLASH_FEE_BASIS_POINTS: u16 = 9;
+const TOKEN_TREASURY_ADDRESS: Address = Address::Account(AccountHash::new([77; 32]));
+const TOKEN_ICON_URL: &str = "https://example.com/csprt.svg";
 
 const TOKEN_OWNER_ADDRESS_1: Address = Address::Account(AccountHash::new([42; 32]));
 const TOKEN_OWNER_AMOUNT_1: u64 = 1_000_000;
 const TOKEN_OWNER_ADDRESS_2: Address = Address::Contract(ContractPackageHash::new([42; 32]));
-const TOKEN_OWNER_AMOUNT_2: u64 = 2_000_000;
 
 #[derive(Default)]
 struct TestToken {
@@ -41,43 +63,82 @@ struct TestToken {
 }
 
 impl TestToken {
-    pub fn install() -> Result<TestToken, Error> {
-        let name: String = TOKEN_NAME.to_string();
-        let symbol: String = TOKEN_SYMBOL.to_string();
-        let decimals = TOKEN_DECIMALS;
-        let total_supply = U256::from(TOKEN_TOTAL_SUPPLY);
-
+    fn entry_points() -> EntryPoints {
         let mut entry_points = EntryPoints::new();
 
-        let mint_entrypoint = EntryPoint::new(
-            MINT_ENTRY_POINT_NAME,
-            vec![
-                Parameter::new(OWNER_RUNTIME_ARG_NAME, Address::cl_type()),
-                Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
-            ],
-            CLType::Unit,
-            // NOTE: For security reasons never use this entrypoint definition in a production
-            // contract. This is marks the entry point as public.
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        let burn_entrypoint = EntryPoint::new(
-            BURN_ENTRY_POINT_NAME,
-            vec![
-                Parameter::new(OWNER_RUNTIME_ARG_NAME, Address::cl_type()),
-                Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
-            ],
+        entry_points.add_entry_point(casper_erc20::entry_points::total_supply());
+        entry_points.add_entry_point(casper_erc20::entry_points::cap());
+        entry_points.add_entry_point(casper_erc20::entry_points::balance_of());
+        entry_points.add_entry_point(casper_erc20::entry_points::transfer());
+        entry_points.add_entry_point(casper_erc20::entry_points::approve());
+        entry_points.add_entry_point(casper_erc20::entry_points::approve_with_expiry());
+        entry_points.add_entry_point(casper_erc20::entry_points::transfer_from());
+        entry_points.add_entry_point(casper_erc20::entry_points::batch_transfer());
+        entry_points.add_entry_point(casper_erc20::entry_points::transfer_and_call());
+        entry_points.add_entry_point(casper_erc20::entry_points::approve_and_call());
+        entry_points.add_entry_point(casper_erc20::entry_points::mint());
+        entry_points.add_entry_point(casper_erc20::entry_points::burn());
+        entry_points.add_entry_point(casper_erc20::entry_points::has_role());
+        entry_points.add_entry_point(casper_erc20::entry_points::grant_role());
+        entry_points.add_entry_point(casper_erc20::entry_points::revoke_role());
+        entry_points.add_entry_point(casper_erc20::entry_points::pause());
+        entry_points.add_entry_point(casper_erc20::entry_points::unpause());
+        entry_points.add_entry_point(casper_erc20::entry_points::paused());
+        entry_points.add_entry_point(casper_erc20::entry_points::freeze());
+        entry_points.add_entry_point(casper_erc20::entry_points::unfreeze());
+        entry_points.add_entry_point(casper_erc20::entry_points::is_frozen());
+        entry_points.add_entry_point(casper_erc20::entry_points::snapshot());
+        entry_points.add_entry_point(casper_erc20::entry_points::balance_of_at());
+        entry_points.add_entry_point(casper_erc20::entry_points::total_supply_at());
+        entry_points.add_entry_point(casper_erc20::entry_points::delegate());
+        entry_points.add_entry_point(casper_erc20::entry_points::delegates());
+        entry_points.add_entry_point(casper_erc20::entry_points::get_votes());
+        entry_points.add_entry_point(casper_erc20::entry_points::get_past_votes());
+        entry_points.add_entry_point(casper_erc20::entry_points::storage_version());
+        entry_points.add_entry_point(casper_erc20::entry_points::max_flash_loan());
+        entry_points.add_entry_point(casper_erc20::entry_points::flash_fee());
+        entry_points.add_entry_point(casper_erc20::entry_points::flash_loan());
+        entry_points.add_entry_point(casper_erc20::entry_points::set_transfer_fee());
+        entry_points.add_entry_point(casper_erc20::entry_points::set_fee_exempt());
+        entry_points.add_entry_point(casper_erc20::entry_points::holder_count());
+        entry_points.add_entry_point(casper_erc20::entry_points::holders());
+        entry_points.add_entry_point(casper_erc20::entry_points::token_metadata());
+        entry_points.add_entry_point(casper_erc20::entry_points::metadata_version());
+        entry_points.add_entry_point(casper_erc20::entry_points::set_metadata());
+        entry_points.add_entry_point(casper_erc20::entry_points::owner());
+        entry_points.add_entry_point(casper_erc20::entry_points::pending_owner());
+        entry_points.add_entry_point(casper_erc20::entry_points::transfer_ownership());
+        entry_points.add_entry_point(casper_erc20::entry_points::accept_ownership());
+        entry_points.add_entry_point(casper_erc20::entry_points::renounce_ownership());
+        entry_points.add_entry_point(casper_erc20::entry_points::configure_minter());
+        entry_points.add_entry_point(casper_erc20::entry_points::remove_minter());
+        entry_points.add_entry_point(casper_erc20::entry_points::minter_allowance());
+        entry_points.add_entry_point(casper_erc20::entry_points::mint_bridged());
+        entry_points.add_entry_point(casper_erc20::entry_points::bridge_transfer());
+        entry_points.add_entry_point(casper_erc20::entry_points::request_bridge_back());
+        entry_points.add_entry_point(casper_erc20::entry_points::bridge_request());
+        entry_points.add_entry_point(casper_erc20::entry_points::bridge_request_count());
+        entry_points.add_entry_point(casper_erc20::entry_points::set_bridge_limits());
+        entry_points.add_entry_point(EntryPoint::new(
+            String::from(FORGET_STORAGE_VERSION_ENTRY_POINT_NAME),
+            Vec::new(),
             CLType::Unit,
-            // NOTE: For security reasons never use this entrypoint definition in a production
-            // contract. This is marks the entry point as public.
             EntryPointAccess::Public,
             EntryPointType::Contract,
-        );
+        ));
+        entry_points
+    }
 
-        entry_points.add_entry_point(casper_erc20::entry_points::total_supply());
-        entry_points.add_entry_point(casper_erc20::entry_points::balance_of());
-        entry_points.add_entry_point(mint_entrypoint);
-        entry_points.add_entry_point(burn_entrypoint);
+    pub fn install(events_mode: EventsMode) -> Result<TestToken, Error> {
+        let name: String = TOKEN_NAME.to_string();
+        let symbol: String = TOKEN_SYMBOL.to_string();
+        let decimals = TOKEN_DECIMALS;
+        let total_supply = U256::from(TOKEN_TOTAL_SUPPLY);
+
+        let entry_points = TestToken::entry_points();
+
+        let mut metadata = BTreeMap::new();
+        metadata.insert(METADATA_ICON_URL.to_string(), TOKEN_ICON_URL.to_string());
 
         // Caution: This test uses `install_custom` without providing default entrypoints as
         // described by ERC20 token standard.
@@ -92,6 +153,30 @@ impl TestToken {
             total_supply,
             TEST_CONTRACT_KEY_NAME,
             entry_points,
+            InstallOptions {
+                events_mode,
+                pausable: true,
+                cap: Some(U256::from(TOKEN_CAP)),
+                snapshots: true,
+                votes: true,
+                upgradable: Some(UpgradeOptions {
+                    package_hash_key_name: TEST_CONTRACT_PACKAGE_KEY_NAME.to_string(),
+                    access_key_name: TEST_CONTRACT_ACCESS_KEY_NAME.to_string(),
+                }),
+                flash_fee_basis_points: Some(TOKEN_FLASH_FEE_BASIS_POINTS),
+                wrapped: false,
+                rebasing: false,
+                holders: true,
+                metadata: Some(metadata),
+                bridge: true,
+                // Fee is only raised by the tests which cover it.
+                transfer_fee: Some(TransferFeeOptions {
+                    basis_points: 0,
+                    minimum: U256::zero(),
+                    treasury: TOKEN_TREASURY_ADDRESS,
+                    exempt: Vec::new(),
+                }),
+            },
         )?;
         Ok(TestToken { erc20 })
     }
@@ -117,6 +202,12 @@ pub extern "C" fn total_supply() {
     runtime::ret(CLValue::from_t(val).unwrap_or_revert());
 }
 
+#[no_mangle]
+pub extern "C" fn cap() {
+    let val = TestToken::default().cap();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub extern "C" fn balance_of() {
     let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
@@ -124,29 +215,427 @@ pub extern "C" fn balance_of() {
     runtime::ret(CLValue::from_t(val).unwrap_or_revert());
 }
 
+#[no_mangle]
+pub extern "C" fn transfer() {
+    let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .transfer(recipient, amount)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn approve() {
+    let spender: Address = runtime::get_named_arg(SPENDER_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .approve(spender, amount)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn approve_with_expiry() {
+    let spender: Address = runtime::get_named_arg(SPENDER_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let expires_at: u64 = runtime::get_named_arg(EXPIRES_AT_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .approve_with_expiry(spender, amount, expires_at)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn transfer_from() {
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .transfer_from(owner, recipient, amount)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn batch_transfer() {
+    let recipients: Vec<Address> = runtime::get_named_arg(RECIPIENTS_RUNTIME_ARG_NAME);
+    let amounts: Vec<U256> = runtime::get_named_arg(AMOUNTS_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .batch_transfer(recipients, amounts)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn transfer_and_call() {
+    let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let data: Bytes = runtime::get_named_arg(DATA_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .transfer_and_call(recipient, amount, data)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn approve_and_call() {
+    let spender: Address = runtime::get_named_arg(SPENDER_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let data: Bytes = runtime::get_named_arg(DATA_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .approve_and_call(spender, amount, data)
+        .unwrap_or_revert();
+}
+
 #[no_mangle]
 pub extern "C" fn mint() {
     let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
     let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
-    TestToken::default().mint(owner, amount).unwrap_or_revert();
+    TestToken::default()
+        .mint_as_minter(owner, amount)
+        .unwrap_or_revert();
 }
 
 #[no_mangle]
 pub extern "C" fn burn() {
     let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
     let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
-    TestToken::default().burn(owner, amount).unwrap_or_revert();
+    TestToken::default()
+        .burn_as_burner(owner, amount)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn has_role() {
+    let role: String = runtime::get_named_arg(ROLE_RUNTIME_ARG_NAME);
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let val = TestToken::default().has_role(&role, address);
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn grant_role() {
+    let role: String = runtime::get_named_arg(ROLE_RUNTIME_ARG_NAME);
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .grant_role(&role, address)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn revoke_role() {
+    let role: String = runtime::get_named_arg(ROLE_RUNTIME_ARG_NAME);
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .revoke_role(&role, address)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn pause() {
+    TestToken::default().pause().unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn unpause() {
+    TestToken::default().unpause().unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn paused() {
+    let val = TestToken::default().paused();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn freeze() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    TestToken::default().freeze(address).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn set_transfer_fee() {
+    let basis_points: u16 = runtime::get_named_arg(BASIS_POINTS_RUNTIME_ARG_NAME);
+    let minimum: U256 = runtime::get_named_arg(MINIMUM_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .set_transfer_fee(basis_points, minimum)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn set_fee_exempt() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let exempt: bool = runtime::get_named_arg(EXEMPT_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .set_fee_exempt(address, exempt)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn unfreeze() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    TestToken::default().unfreeze(address).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn is_frozen() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let val = TestToken::default().is_frozen(address);
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn snapshot() {
+    let val = TestToken::default().snapshot().unwrap_or_revert();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn balance_of_at() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let snapshot_id: u64 = runtime::get_named_arg(SNAPSHOT_ID_RUNTIME_ARG_NAME);
+    let val = TestToken::default()
+        .balance_of_at(address, snapshot_id)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn total_supply_at() {
+    let snapshot_id: u64 = runtime::get_named_arg(SNAPSHOT_ID_RUNTIME_ARG_NAME);
+    let val = TestToken::default()
+        .total_supply_at(snapshot_id)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn delegate() {
+    let delegatee: Address = runtime::get_named_arg(DELEGATEE_RUNTIME_ARG_NAME);
+    TestToken::default().delegate(delegatee).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn delegates() {
+    let account: Address = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+    let val = TestToken::default().delegates(account);
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_votes() {
+    let account: Address = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+    let val = TestToken::default().get_votes(account);
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_past_votes() {
+    let account: Address = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+    let block_time: u64 = runtime::get_named_arg(BLOCK_TIME_RUNTIME_ARG_NAME);
+    let val = TestToken::default()
+        .get_past_votes(account, block_time)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn storage_version() {
+    let val = TestToken::default().storage_version();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+/// Removes the storage version marker, so that the contract looks like one installed with an
+/// older storage layout. Only used to test that such contracts are not upgraded.
+#[no_mangle]
+pub extern "C" fn forget_storage_version() {
+    runtime::remove_key(STORAGE_VERSION_KEY_NAME);
+}
+
+#[no_mangle]
+pub extern "C" fn max_flash_loan() {
+    let val = TestToken::default().max_flash_loan();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn flash_fee() {
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let val = TestToken::default().flash_fee(amount).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn holder_count() {
+    let val = TestToken::default().holder_count();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn holders() {
+    let offset: u64 = runtime::get_named_arg(OFFSET_RUNTIME_ARG_NAME);
+    let limit: u64 = runtime::get_named_arg(LIMIT_RUNTIME_ARG_NAME);
+    let val = TestToken::default().holders(offset, limit);
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn token_metadata() {
+    let val = TestToken::default().token_metadata();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn metadata_version() {
+    let val = TestToken::default().metadata_version();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_metadata() {
+    let key: String = runtime::get_named_arg(KEY_RUNTIME_ARG_NAME);
+    let value: String = runtime::get_named_arg(VALUE_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .set_metadata(key, value)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn owner() {
+    let val = TestToken::default().owner();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn pending_owner() {
+    let val = TestToken::default().pending_owner();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn transfer_ownership() {
+    let new_owner: Address = runtime::get_named_arg(NEW_OWNER_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .transfer_ownership(new_owner)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn accept_ownership() {
+    TestToken::default().accept_ownership().unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn renounce_ownership() {
+    TestToken::default().renounce_ownership().unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn configure_minter() {
+    let minter: Address = runtime::get_named_arg(MINTER_RUNTIME_ARG_NAME);
+    let allowance: U256 = runtime::get_named_arg(ALLOWANCE_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .configure_minter(minter, allowance)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn remove_minter() {
+    let minter: Address = runtime::get_named_arg(MINTER_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .remove_minter(minter)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn minter_allowance() {
+    let minter: Address = runtime::get_named_arg(MINTER_RUNTIME_ARG_NAME);
+    let val = TestToken::default().minter_allowance(minter);
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn mint_bridged() {
+    let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let source_chain_id: u64 = runtime::get_named_arg(SOURCE_CHAIN_ID_RUNTIME_ARG_NAME);
+    let source_tx_hash: Bytes = runtime::get_named_arg(SOURCE_TX_HASH_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .mint_bridged(recipient, amount, source_chain_id, source_tx_hash)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn bridge_transfer() {
+    let source_chain_id: u64 = runtime::get_named_arg(SOURCE_CHAIN_ID_RUNTIME_ARG_NAME);
+    let source_tx_hash: Bytes = runtime::get_named_arg(SOURCE_TX_HASH_RUNTIME_ARG_NAME);
+    let val = TestToken::default().bridge_transfer(source_chain_id, source_tx_hash);
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn request_bridge_back() {
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let destination_chain_id: u64 = runtime::get_named_arg(DESTINATION_CHAIN_ID_RUNTIME_ARG_NAME);
+    let destination_address: Vec<u8> = runtime::get_named_arg(DESTINATION_ADDRESS_RUNTIME_ARG_NAME);
+    let val = TestToken::default()
+        .request_bridge_back(amount, destination_chain_id, destination_address)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn bridge_request() {
+    let index: u64 = runtime::get_named_arg(INDEX_RUNTIME_ARG_NAME);
+    let val = TestToken::default().bridge_request(index);
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn bridge_request_count() {
+    let val = TestToken::default().bridge_request_count();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_bridge_limits() {
+    let destination_chain_id: u64 = runtime::get_named_arg(DESTINATION_CHAIN_ID_RUNTIME_ARG_NAME);
+    let minimum: U256 = runtime::get_named_arg(MINIMUM_RUNTIME_ARG_NAME);
+    let maximum: U256 = runtime::get_named_arg(MAXIMUM_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .set_bridge_limits(destination_chain_id, minimum, maximum)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn flash_loan() {
+    let receiver: Address = runtime::get_named_arg(RECEIVER_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let data: Bytes = runtime::get_named_arg(DATA_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .flash_loan(receiver, amount, data)
+        .unwrap_or_revert();
 }
 
 #[no_mangle]
 fn call() {
-    let mut test_token = TestToken::install().unwrap_or_revert();
+    let upgrade: bool = runtime::get_named_arg(UPGRADE_RUNTIME_ARG_NAME);
+    if upgrade {
+        ERC20::upgrade(
+            TEST_CONTRACT_PACKAGE_KEY_NAME,
+            TEST_CONTRACT_KEY_NAME,
+            TestToken::entry_points(),
+        )
+        .unwrap_or_revert();
+        return;
+    }
+
+    let events_mode: u8 = runtime::get_named_arg(EVENTS_MODE_RUNTIME_ARG_NAME);
+    let events_mode = EventsMode::try_from(events_mode).unwrap_or_revert();
+    let token_owner_amount_2: U256 = runtime::get_named_arg(TOKEN_OWNER_AMOUNT_2_RUNTIME_ARG_NAME);
+
+    let mut test_token = TestToken::install(events_mode).unwrap_or_revert();
 
     test_token
         .mint(TOKEN_OWNER_ADDRESS_1, U256::from(TOKEN_OWNER_AMOUNT_1))
         .unwrap_or_revert();
 
     test_token
-        .mint(TOKEN_OWNER_ADDRESS_2, U256::from(TOKEN_OWNER_AMOUNT_2))
+        .mint(TOKEN_OWNER_ADDRESS_2, token_owner_amount_2)
         .unwrap_or_revert();
 }
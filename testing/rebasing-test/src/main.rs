@@ -0,0 +1,103 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::string::ToString;
+
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_erc20::{
+    constants::{
+        ADDRESS_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME, OWNER_RUNTIME_ARG_NAME,
+        RECIPIENT_RUNTIME_ARG_NAME, TOTAL_SUPPLY_RUNTIME_ARG_NAME,
+    },
+    Address, InstallOptions, ERC20,
+};
+use casper_types::{CLValue, EntryPoints, U256};
+
+const REBASING_TEST_CONTRACT_KEY_NAME: &str = "rebasing_test_contract";
+const SNAPSHOTS_RUNTIME_ARG_NAME: &str = "snapshots";
+const VOTES_RUNTIME_ARG_NAME: &str = "votes";
+const TOKEN_NAME: &str = "Rebasing";
+const TOKEN_SYMBOL: &str = "REB";
+const TOKEN_DECIMALS: u8 = 8;
+const TOKEN_TOTAL_SUPPLY: u64 = 1_000;
+
+fn entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(casper_erc20::entry_points::total_supply());
+    entry_points.add_entry_point(casper_erc20::entry_points::balance_of());
+    entry_points.add_entry_point(casper_erc20::entry_points::transfer());
+    entry_points.add_entry_point(casper_erc20::entry_points::mint());
+    entry_points.add_entry_point(casper_erc20::entry_points::burn());
+    entry_points.add_entry_point(casper_erc20::entry_points::rebase());
+    entry_points
+}
+
+#[no_mangle]
+pub extern "C" fn total_supply() {
+    let val = ERC20::default().total_supply();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn balance_of() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let val = ERC20::default().balance_of(address);
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn transfer() {
+    let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    ERC20::default()
+        .transfer(recipient, amount)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn mint() {
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    ERC20::default()
+        .mint_as_minter(owner, amount)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn burn() {
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    ERC20::default()
+        .burn_as_burner(owner, amount)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn rebase() {
+    let total_supply: U256 = runtime::get_named_arg(TOTAL_SUPPLY_RUNTIME_ARG_NAME);
+    ERC20::default().rebase(total_supply).unwrap_or_revert();
+}
+
+#[no_mangle]
+fn call() {
+    let snapshots: bool = runtime::get_named_arg(SNAPSHOTS_RUNTIME_ARG_NAME);
+    let votes: bool = runtime::get_named_arg(VOTES_RUNTIME_ARG_NAME);
+
+    ERC20::install_custom(
+        TOKEN_NAME.to_string(),
+        TOKEN_SYMBOL.to_string(),
+        TOKEN_DECIMALS,
+        U256::from(TOKEN_TOTAL_SUPPLY),
+        REBASING_TEST_CONTRACT_KEY_NAME,
+        entry_points(),
+        InstallOptions {
+            rebasing: true,
+            snapshots,
+            votes,
+            ..InstallOptions::default()
+        },
+    )
+    .unwrap_or_revert();
+}
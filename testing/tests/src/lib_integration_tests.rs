@@ -1,3 +1,9 @@
+use std::collections::BTreeMap;
+
+use blake2::{
+    digest::{Update, VariableOutput},
+    VarBlake2b,
+};
 use once_cell::sync::Lazy;
 
 use casper_engine_test_support::{
@@ -9,13 +15,46 @@ use casper_execution_engine::core::{
     execution::Error as ExecError,
 };
 use casper_types::{
-    account::AccountHash, bytesrepr::FromBytes, runtime_args, system::mint, ApiError, CLTyped,
-    ContractHash, ContractPackageHash, Key, PublicKey, RuntimeArgs, SecretKey, U256,
+    account::AccountHash,
+    bytesrepr::{Bytes, FromBytes, ToBytes},
+    crypto, runtime_args,
+    system::mint,
+    ApiError, CLTyped, ContractHash, ContractPackageHash, Key, PublicKey, RuntimeArgs, SecretKey,
+    URef, U256, U512,
 };
 
+mod access_control;
+mod allowance_expiry;
+mod allowances;
+mod batch_transfer;
+mod blocklist;
+mod bridge;
+mod cap;
+mod ces;
+mod events;
+mod flash_mint;
+mod holders;
+mod metadata;
+mod minter_allowances;
+mod ownership;
+mod pausable;
+mod permit;
+mod rebasing;
+mod snapshots;
+mod transfer_and_call;
+mod transfer_fee;
+mod upgrade;
+mod vesting;
+mod votes;
+mod wrapped;
+
 const EXAMPLE_ERC20_TOKEN: &str = "erc20_token.wasm";
 const CONTRACT_ERC20_TEST: &str = "erc20_test.wasm";
 const CONTRACT_ERC20_TEST_CALL: &str = "erc20_test_call.wasm";
+const CONTRACT_WCSPR_TEST: &str = "wcspr_test.wasm";
+const SESSION_WCSPR_DEPOSIT: &str = "wcspr_deposit.wasm";
+const EXAMPLE_ERC20_VESTING: &str = "erc20_vesting.wasm";
+const CONTRACT_REBASING_TEST: &str = "rebasing_test.wasm";
 const NAME_KEY: &str = "name";
 const SYMBOL_KEY: &str = "symbol";
 const ERC20_TOKEN_CONTRACT_KEY: &str = "erc20_token_contract";
@@ -23,23 +62,63 @@ const DECIMALS_KEY: &str = "decimals";
 const TOTAL_SUPPLY_KEY: &str = "total_supply";
 const BALANCES_KEY: &str = "balances";
 const ALLOWANCES_KEY: &str = "allowances";
+const EVENTS_KEY: &str = "events";
+const EVENTS_COUNT_KEY: &str = "events_count";
+const NONCES_KEY: &str = "nonces";
+const CES_EVENTS_KEY: &str = "__events";
+const CES_EVENTS_LENGTH_KEY: &str = "__events_length";
+const CES_EVENTS_SCHEMA_KEY: &str = "__events_schema";
+const CES_VERSION_KEY: &str = "__events_ces_version";
 
 const ARG_NAME: &str = "name";
 const ARG_SYMBOL: &str = "symbol";
 const ARG_DECIMALS: &str = "decimals";
 const ARG_TOTAL_SUPPLY: &str = "total_supply";
+const ARG_EVENTS_MODE: &str = "events_mode";
+const ARG_UPGRADE: &str = "upgrade";
+const ARG_TOKEN_OWNER_AMOUNT_2: &str = "token_owner_amount_2";
+
+const EVENTS_MODE_NO_EVENTS: u8 = 0;
+const EVENTS_MODE_NATIVE: u8 = 1;
+const EVENTS_MODE_CES: u8 = 2;
 
 const TEST_CONTRACT_KEY: &str = "test_contract";
+const TEST_CONTRACT_PACKAGE_KEY: &str = "test_contract_package";
+const STORAGE_VERSION_KEY: &str = "storage_version";
 
 const _ERROR_INVALID_CONTEXT: u16 = u16::MAX;
 const ERROR_INSUFFICIENT_BALANCE: u16 = u16::MAX - 1;
 const ERROR_INSUFFICIENT_ALLOWANCE: u16 = u16::MAX - 2;
 const ERROR_OVERFLOW: u16 = u16::MAX - 3;
+const ERROR_INVALID_SIGNATURE: u16 = u16::MAX - 5;
+const ERROR_PERMIT_EXPIRED: u16 = u16::MAX - 6;
+const ERROR_INVALID_NONCE: u16 = u16::MAX - 7;
+const ERROR_MISSING_ROLE: u16 = u16::MAX - 8;
+const ERROR_PAUSED: u16 = u16::MAX - 9;
+const ERROR_CAP_EXCEEDED: u16 = u16::MAX - 10;
+const ERROR_ACCOUNT_FROZEN: u16 = u16::MAX - 11;
+const ERROR_INVALID_SNAPSHOT_ID: u16 = u16::MAX - 12;
+const ERROR_FUTURE_LOOKUP: u16 = u16::MAX - 13;
+const ERROR_INCOMPATIBLE_STORAGE_VERSION: u16 = u16::MAX - 14;
+const ERROR_LENGTH_MISMATCH: u16 = u16::MAX - 15;
+const ERROR_RECEIVER_REJECTED: u16 = u16::MAX - 16;
+const ERROR_INVALID_DECIMALS: u16 = u16::MAX - 17;
+const ERROR_TRANSFER_FEE_TOO_HIGH: u16 = u16::MAX - 18;
+const ERROR_INVALID_REBASE: u16 = u16::MAX - 19;
+const ERROR_ALLOWANCE_EXPIRED: u16 = u16::MAX - 20;
+const ERROR_NOT_OWNER: u16 = u16::MAX - 21;
+const ERROR_MINTER_ALLOWANCE_EXCEEDED: u16 = u16::MAX - 22;
+const ERROR_BRIDGE_TRANSFER_PROCESSED: u16 = u16::MAX - 23;
+const ERROR_UNSUPPORTED_CHAIN: u16 = u16::MAX - 24;
+const ERROR_BRIDGE_AMOUNT_OUT_OF_RANGE: u16 = u16::MAX - 25;
+const ERROR_UNBACKED_SUPPLY: u16 = u16::MAX - 26;
+const ERROR_REBASING_NOT_SUPPORTED: u16 = u16::MAX - 27;
 
 const TOKEN_NAME: &str = "CasperTest";
 const TOKEN_SYMBOL: &str = "CSPRT";
 const TOKEN_DECIMALS: u8 = 100;
 const TOKEN_TOTAL_SUPPLY: u64 = 1_000_000_000;
+const TOKEN_CAP: u64 = 2_000_000_000;
 
 const METHOD_TRANSFER: &str = "transfer";
 const ARG_AMOUNT: &str = "amount";
@@ -50,10 +129,22 @@ const ARG_OWNER: &str = "owner";
 const ARG_SPENDER: &str = "spender";
 
 const METHOD_TRANSFER_FROM: &str = "transfer_from";
+const METHOD_INCREASE_ALLOWANCE: &str = "increase_allowance";
+const METHOD_DECREASE_ALLOWANCE: &str = "decrease_allowance";
+
+const METHOD_PERMIT: &str = "permit";
+const ARG_DEADLINE: &str = "deadline";
+const ARG_NONCE: &str = "nonce";
+const ARG_SIGNATURE: &str = "signature";
+const PERMIT_DOMAIN: &str = "casper-erc20:permit";
 
 const CHECK_TOTAL_SUPPLY_ENTRYPOINT: &str = "check_total_supply";
 const CHECK_BALANCE_OF_ENTRYPOINT: &str = "check_balance_of";
 const CHECK_ALLOWANCE_OF_ENTRYPOINT: &str = "check_allowance_of";
+const CHECK_BALANCE_OF_AT_ENTRYPOINT: &str = "check_balance_of_at";
+const CHECK_TOTAL_SUPPLY_AT_ENTRYPOINT: &str = "check_total_supply_at";
+const CHECK_HOLDER_COUNT_ENTRYPOINT: &str = "check_holder_count";
+const CHECK_HOLDERS_ENTRYPOINT: &str = "check_holders";
 const ARG_TOKEN_CONTRACT: &str = "token_contract";
 const ARG_ADDRESS: &str = "address";
 const RESULT_KEY: &str = "result";
@@ -88,6 +179,52 @@ const TOKEN_OWNER_AMOUNT_2: u64 = 2_000_000;
 const METHOD_MINT: &str = "mint";
 const METHOD_BURN: &str = "burn";
 
+const METHOD_GRANT_ROLE: &str = "grant_role";
+const METHOD_REVOKE_ROLE: &str = "revoke_role";
+const ARG_ROLE: &str = "role";
+const MINTER_ROLE: &str = "minter";
+
+const METHOD_PAUSE: &str = "pause";
+const METHOD_UNPAUSE: &str = "unpause";
+const PAUSED_KEY: &str = "paused";
+const CAP_KEY: &str = "cap";
+
+const METHOD_FREEZE: &str = "freeze";
+const METHOD_UNFREEZE: &str = "unfreeze";
+
+const METHOD_SNAPSHOT: &str = "snapshot";
+const ARG_SNAPSHOT_ID: &str = "snapshot_id";
+
+const METHOD_BATCH_TRANSFER: &str = "batch_transfer";
+const ARG_RECIPIENTS: &str = "recipients";
+const ARG_AMOUNTS: &str = "amounts";
+
+const METHOD_TRANSFER_AND_CALL: &str = "transfer_and_call";
+const METHOD_APPROVE_AND_CALL: &str = "approve_and_call";
+const ARG_DATA: &str = "data";
+const REJECT_DATA: &[u8] = b"reject";
+
+const METHOD_FLASH_LOAN: &str = "flash_loan";
+const ARG_RECEIVER: &str = "receiver";
+const KEEP_DATA: &[u8] = b"keep";
+const TOKEN_FLASH_FEE_BASIS_POINTS: u64 = 9;
+
+const METHOD_DELEGATE: &str = "delegate";
+const METHOD_GET_PAST_VOTES: &str = "get_past_votes";
+const ARG_DELEGATEE: &str = "delegatee";
+const ARG_ACCOUNT: &str = "account";
+const ARG_BLOCK_TIME: &str = "block_time";
+const DELEGATES_KEY: &str = "delegates";
+const VOTES_KEY: &str = "votes";
+
+fn blake2b256(preimage: &[u8]) -> [u8; 32] {
+    let mut hasher = VarBlake2b::new(32).unwrap();
+    hasher.update(preimage);
+    let mut hash = [0u8; 32];
+    hasher.finalize_variable(|result| hash.copy_from_slice(result));
+    hash
+}
+
 /// Converts hash addr of Account into Hash, and Hash into Account
 ///
 /// This is useful for making sure ERC20 library respects different variants of Key when storing
@@ -107,6 +244,21 @@ struct TestContext {
     erc20_test_call: ContractPackageHash,
 }
 
+/// Makes a request which installs the test token recording events in `events_mode`, and then
+/// mints to both of the token owners from the installing session.
+fn make_erc20_test_install_request(events_mode: u8, token_owner_amount_2: U256) -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_ERC20_TEST,
+        runtime_args! {
+            ARG_EVENTS_MODE => events_mode,
+            ARG_UPGRADE => false,
+            ARG_TOKEN_OWNER_AMOUNT_2 => token_owner_amount_2,
+        },
+    )
+    .build()
+}
+
 fn setup() -> (InMemoryWasmTestBuilder, TestContext) {
     let mut builder = InMemoryWasmTestBuilder::default();
     builder.run_genesis(&*DEFAULT_RUN_GENESIS_REQUEST);
@@ -139,12 +291,8 @@ fn setup() -> (InMemoryWasmTestBuilder, TestContext) {
         },
     )
     .build();
-    let install_request_2 = ExecuteRequestBuilder::standard(
-        *DEFAULT_ACCOUNT_ADDR,
-        CONTRACT_ERC20_TEST,
-        RuntimeArgs::default(),
-    )
-    .build();
+    let install_request_2 =
+        make_erc20_test_install_request(EVENTS_MODE_NATIVE, U256::from(TOKEN_OWNER_AMOUNT_2));
     let install_request_3 = ExecuteRequestBuilder::standard(
         *DEFAULT_ACCOUNT_ADDR,
         CONTRACT_ERC20_TEST_CALL,
@@ -241,6 +389,32 @@ fn get_test_result<T: FromBytes + CLTyped>(
     builder.get_value(*contract_hash, RESULT_KEY)
 }
 
+fn erc20_get_dictionary_value<T: FromBytes + CLTyped>(
+    builder: &InMemoryWasmTestBuilder,
+    erc20_contract_hash: &ContractHash,
+    dictionary_name: &str,
+    dictionary_item_key: &str,
+) -> T {
+    let contract = builder
+        .get_contract(*erc20_contract_hash)
+        .expect("should have contract");
+    let dictionary_uref = contract
+        .named_keys()
+        .get(dictionary_name)
+        .and_then(Key::as_uref)
+        .expect("should have dictionary uref");
+    let dictionary_key = Key::dictionary(*dictionary_uref, dictionary_item_key.as_bytes());
+
+    builder
+        .query(None, dictionary_key, &[])
+        .expect("should have dictionary value")
+        .as_cl_value()
+        .cloned()
+        .expect("should have CLValue")
+        .into_t()
+        .expect("should have correct type")
+}
+
 fn erc20_check_balance_of(
     builder: &mut InMemoryWasmTestBuilder,
     erc20_contract_hash: &ContractHash,
@@ -442,6 +616,16 @@ fn make_erc20_approve_request(
     }
 }
 
+/// Makes a request which calls the `method` entry point of `erc20_token` with `args`.
+fn make_erc20_request(
+    sender: AccountHash,
+    erc20_token: &ContractHash,
+    method: &str,
+    args: RuntimeArgs,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(sender, *erc20_token, method, args).build()
+}
+
 fn test_approve_for(
     builder: &mut InMemoryWasmTestBuilder,
     test_context: &TestContext,
@@ -1322,3 +1506,134 @@ fn should_verify_zero_amount_transfer_from_is_noop() {
     let spender_allowance_after = erc20_check_allowance_of(&mut builder, owner, spender);
     assert_eq!(spender_allowance_after, spender_allowance_before);
 }
+
+/// Installs another test token which records events in `events_mode`, and returns its hash.
+fn install_erc20_test(builder: &mut InMemoryWasmTestBuilder, events_mode: u8) -> ContractHash {
+    let install_request =
+        make_erc20_test_install_request(events_mode, U256::from(TOKEN_OWNER_AMOUNT_2));
+    builder.exec(install_request).expect_success().commit();
+
+    get_test_contract_hash(builder)
+}
+
+/// Returns the hash of the test token installed last.
+fn get_test_contract_hash(builder: &InMemoryWasmTestBuilder) -> ContractHash {
+    builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have contract hash")
+}
+
+fn make_erc20_mint_request(
+    sender: AccountHash,
+    erc20_token: &ContractHash,
+    owner: Key,
+    amount: U256,
+) -> ExecuteRequest {
+    make_erc20_request(
+        sender,
+        erc20_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => owner,
+            ARG_AMOUNT => amount,
+        },
+    )
+}
+
+fn make_erc20_role_request(
+    sender: AccountHash,
+    erc20_token: &ContractHash,
+    method: &str,
+    role: &str,
+    address: Key,
+) -> ExecuteRequest {
+    make_erc20_request(
+        sender,
+        erc20_token,
+        method,
+        runtime_args! {
+            ARG_ROLE => role,
+            ARG_ADDRESS => address,
+        },
+    )
+}
+
+fn make_erc20_freeze_request(
+    sender: AccountHash,
+    erc20_token: &ContractHash,
+    method: &str,
+    address: Key,
+) -> ExecuteRequest {
+    make_erc20_request(
+        sender,
+        erc20_token,
+        method,
+        runtime_args! {
+            ARG_ADDRESS => address,
+        },
+    )
+}
+
+fn make_erc20_check_at_request(
+    entry_point: &str,
+    erc20_test_call: ContractPackageHash,
+    args: RuntimeArgs,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        erc20_test_call,
+        None,
+        entry_point,
+        args,
+    )
+    .build()
+}
+
+const METHOD_SET_TRANSFER_FEE: &str = "set_transfer_fee";
+const METHOD_SET_FEE_EXEMPT: &str = "set_fee_exempt";
+const ARG_BASIS_POINTS: &str = "basis_points";
+const ARG_MINIMUM: &str = "minimum";
+const ARG_EXEMPT: &str = "exempt";
+const TRANSFER_FEE_BASIS_POINTS: u16 = 100;
+const TRANSFER_FEE_MINIMUM: u64 = 50;
+
+const METHOD_SET_METADATA: &str = "set_metadata";
+const ARG_KEY: &str = "key";
+const ARG_VALUE: &str = "value";
+const METADATA_WEBSITE_URL: &str = "website_url";
+const TOKEN_WEBSITE_URL: &str = "https://example.com";
+
+const METHOD_CONFIGURE_MINTER: &str = "configure_minter";
+const METHOD_REMOVE_MINTER: &str = "remove_minter";
+const ARG_MINTER: &str = "minter";
+const ARG_ALLOWANCE: &str = "allowance";
+const MINTER_ALLOWANCE: u64 = 100;
+
+fn make_erc20_configure_minter_request(
+    sender: AccountHash,
+    erc20_token: &ContractHash,
+    minter: Key,
+    allowance: U256,
+) -> ExecuteRequest {
+    make_erc20_request(
+        sender,
+        erc20_token,
+        METHOD_CONFIGURE_MINTER,
+        runtime_args! {
+            ARG_MINTER => minter,
+            ARG_ALLOWANCE => allowance,
+        },
+    )
+}
+
+const METHOD_SET_BRIDGE_LIMITS: &str = "set_bridge_limits";
+const ARG_DESTINATION_CHAIN_ID: &str = "destination_chain_id";
+const ARG_MAXIMUM: &str = "maximum";
+const DESTINATION_CHAIN_ID: u64 = 2;
+const BRIDGE_MINIMUM: u64 = 100;
+const BRIDGE_MAXIMUM: u64 = 1_000;
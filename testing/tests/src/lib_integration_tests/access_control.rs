@@ -0,0 +1,113 @@
+use super::*;
+
+#[test]
+fn should_only_allow_minters_to_mint() {
+    let mint_amount = U256::one();
+
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let minter = Key::Account(*ACCOUNT_1_ADDR);
+
+    // Installer is the only minter which can mint without being configured with an allowance.
+    let mint_request =
+        make_erc20_mint_request(*DEFAULT_ACCOUNT_ADDR, &test_contract, minter, mint_amount);
+    builder.exec(mint_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, minter),
+        mint_amount
+    );
+
+    let mint_request =
+        make_erc20_mint_request(*ACCOUNT_1_ADDR, &test_contract, minter, mint_amount);
+    builder.exec(mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_MISSING_ROLE),
+        "{:?}",
+        error
+    );
+
+    let grant_role_request = make_erc20_role_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_GRANT_ROLE,
+        MINTER_ROLE,
+        minter,
+    );
+    builder.exec(grant_role_request).expect_success().commit();
+
+    // Minter role alone comes with no allowance.
+    let mint_request =
+        make_erc20_mint_request(*ACCOUNT_1_ADDR, &test_contract, minter, mint_amount);
+    builder.exec(mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_MINTER_ALLOWANCE_EXCEEDED),
+        "{:?}",
+        error
+    );
+
+    let configure_minter_request = make_erc20_configure_minter_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        minter,
+        mint_amount,
+    );
+    builder
+        .exec(configure_minter_request)
+        .expect_success()
+        .commit();
+
+    let mint_request =
+        make_erc20_mint_request(*ACCOUNT_1_ADDR, &test_contract, minter, mint_amount);
+    builder.exec(mint_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, minter),
+        mint_amount * 2
+    );
+
+    let revoke_role_request = make_erc20_role_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_REVOKE_ROLE,
+        MINTER_ROLE,
+        minter,
+    );
+    builder.exec(revoke_role_request).expect_success().commit();
+
+    let mint_request =
+        make_erc20_mint_request(*ACCOUNT_1_ADDR, &test_contract, minter, mint_amount);
+    builder.exec(mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_MISSING_ROLE),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_only_allow_admin_to_grant_roles() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let grant_role_request = make_erc20_role_request(
+        *ACCOUNT_1_ADDR,
+        &test_contract,
+        METHOD_GRANT_ROLE,
+        MINTER_ROLE,
+        Key::Account(*ACCOUNT_1_ADDR),
+    );
+    builder.exec(grant_role_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_MISSING_ROLE),
+        "{:?}",
+        error
+    );
+}
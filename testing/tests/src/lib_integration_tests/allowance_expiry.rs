@@ -0,0 +1,198 @@
+use super::*;
+
+const METHOD_APPROVE_WITH_EXPIRY: &str = "approve_with_expiry";
+const ARG_EXPIRES_AT: &str = "expires_at";
+
+fn erc20_check_allowance_at_block_time(
+    builder: &mut InMemoryWasmTestBuilder,
+    erc20_contract_hash: &ContractHash,
+    erc20_test_call: ContractPackageHash,
+    owner: Key,
+    spender: Key,
+    block_time: u64,
+) -> U256 {
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        erc20_test_call,
+        None,
+        CHECK_ALLOWANCE_OF_ENTRYPOINT,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => *erc20_contract_hash,
+            ARG_OWNER => owner,
+            ARG_SPENDER => spender,
+        },
+    )
+    .with_block_time(block_time)
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, erc20_test_call)
+}
+
+fn make_erc20_transfer_from_request_at_block_time(
+    sender: AccountHash,
+    erc20_token: &ContractHash,
+    owner: Key,
+    recipient: Key,
+    amount: U256,
+    block_time: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        *erc20_token,
+        METHOD_TRANSFER_FROM,
+        runtime_args! {
+            ARG_OWNER => owner,
+            ARG_RECIPIENT => recipient,
+            ARG_AMOUNT => amount,
+        },
+    )
+    .with_block_time(block_time)
+    .build()
+}
+
+#[test]
+fn should_not_spend_allowance_after_expiry() {
+    let (
+        mut builder,
+        TestContext {
+            test_contract,
+            erc20_test_call,
+            ..
+        },
+    ) = setup();
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = Key::Account(*ACCOUNT_1_ADDR);
+    let recipient = Key::Account(*ACCOUNT_2_ADDR);
+    let allowance_amount = U256::from(ALLOWANCE_AMOUNT_1);
+    let transfer_amount = U256::one();
+    let expires_at = 10_000u64;
+
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_APPROVE_WITH_EXPIRY,
+        runtime_args! {
+            ARG_SPENDER => spender,
+            ARG_AMOUNT => allowance_amount,
+            ARG_EXPIRES_AT => expires_at,
+        },
+    )
+    .with_block_time(1_000)
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_allowance_at_block_time(
+            &mut builder,
+            &test_contract,
+            erc20_test_call,
+            owner,
+            spender,
+            expires_at - 1,
+        ),
+        allowance_amount
+    );
+
+    let transfer_from_request = make_erc20_transfer_from_request_at_block_time(
+        *ACCOUNT_1_ADDR,
+        &test_contract,
+        owner,
+        recipient,
+        transfer_amount,
+        expires_at - 1,
+    );
+    builder
+        .exec(transfer_from_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        erc20_check_allowance_at_block_time(
+            &mut builder,
+            &test_contract,
+            erc20_test_call,
+            owner,
+            spender,
+            expires_at,
+        ),
+        U256::zero()
+    );
+
+    let transfer_from_request = make_erc20_transfer_from_request_at_block_time(
+        *ACCOUNT_1_ADDR,
+        &test_contract,
+        owner,
+        recipient,
+        transfer_amount,
+        expires_at,
+    );
+    builder.exec(transfer_from_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_ALLOWANCE_EXPIRED),
+        "{:?}",
+        error
+    );
+
+    // Increasing an expired allowance starts from zero, and the result no longer expires.
+    let increase_allowance_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_INCREASE_ALLOWANCE,
+        runtime_args! {
+            ARG_SPENDER => spender,
+            ARG_AMOUNT => transfer_amount,
+        },
+    )
+    .with_block_time(expires_at)
+    .build();
+    builder
+        .exec(increase_allowance_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        erc20_check_allowance_at_block_time(
+            &mut builder,
+            &test_contract,
+            erc20_test_call,
+            owner,
+            spender,
+            expires_at * 2,
+        ),
+        transfer_amount
+    );
+
+    let transfer_from_request = make_erc20_transfer_from_request_at_block_time(
+        *ACCOUNT_1_ADDR,
+        &test_contract,
+        owner,
+        recipient,
+        transfer_amount,
+        expires_at * 2,
+    );
+    builder
+        .exec(transfer_from_request)
+        .expect_success()
+        .commit();
+
+    // Plain approvals set an allowance which never expires.
+    let approve_request =
+        make_erc20_approve_request(owner, &test_contract, spender, allowance_amount);
+    builder.exec(approve_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_allowance_at_block_time(
+            &mut builder,
+            &test_contract,
+            erc20_test_call,
+            owner,
+            spender,
+            expires_at * 2,
+        ),
+        allowance_amount
+    );
+}
@@ -0,0 +1,157 @@
+use super::*;
+
+#[test]
+fn should_increase_and_decrease_allowance() {
+    let (mut builder, TestContext { erc20_token, .. }) = setup();
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = Key::Account(*ACCOUNT_1_ADDR);
+
+    let allowance_amount_1 = U256::from(ALLOWANCE_AMOUNT_1);
+    let allowance_amount_2 = U256::from(ALLOWANCE_AMOUNT_2);
+
+    let approve_request =
+        make_erc20_approve_request(owner, &erc20_token, spender, allowance_amount_1);
+    builder.exec(approve_request).expect_success().commit();
+
+    let increase_allowance_request = make_erc20_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &erc20_token,
+        METHOD_INCREASE_ALLOWANCE,
+        runtime_args! {
+            ARG_SPENDER => spender,
+            ARG_AMOUNT => allowance_amount_2,
+        },
+    );
+    builder
+        .exec(increase_allowance_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        erc20_check_allowance_of(&mut builder, owner, spender),
+        allowance_amount_1 + allowance_amount_2
+    );
+
+    let decrease_allowance_request = make_erc20_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &erc20_token,
+        METHOD_DECREASE_ALLOWANCE,
+        runtime_args! {
+            ARG_SPENDER => spender,
+            ARG_AMOUNT => allowance_amount_1,
+        },
+    );
+    builder
+        .exec(decrease_allowance_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        erc20_check_allowance_of(&mut builder, owner, spender),
+        allowance_amount_2
+    );
+}
+
+#[test]
+fn should_not_decrease_allowance_below_zero() {
+    let (mut builder, TestContext { erc20_token, .. }) = setup();
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = Key::Account(*ACCOUNT_1_ADDR);
+
+    let allowance_amount = U256::from(ALLOWANCE_AMOUNT_2);
+
+    let approve_request =
+        make_erc20_approve_request(owner, &erc20_token, spender, allowance_amount);
+    builder.exec(approve_request).expect_success().commit();
+
+    let decrease_allowance_request = make_erc20_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &erc20_token,
+        METHOD_DECREASE_ALLOWANCE,
+        runtime_args! {
+            ARG_SPENDER => spender,
+            ARG_AMOUNT => allowance_amount + U256::one(),
+        },
+    );
+    builder.exec(decrease_allowance_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_ALLOWANCE),
+        "{:?}",
+        error
+    );
+
+    assert_eq!(
+        erc20_check_allowance_of(&mut builder, owner, spender),
+        allowance_amount
+    );
+}
+
+#[test]
+fn should_not_increase_allowance_above_limits() {
+    let (mut builder, TestContext { erc20_token, .. }) = setup();
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = Key::Account(*ACCOUNT_1_ADDR);
+
+    let approve_request = make_erc20_approve_request(owner, &erc20_token, spender, U256::one());
+    builder.exec(approve_request).expect_success().commit();
+
+    let increase_allowance_request = make_erc20_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &erc20_token,
+        METHOD_INCREASE_ALLOWANCE,
+        runtime_args! {
+            ARG_SPENDER => spender,
+            ARG_AMOUNT => U256::MAX,
+        },
+    );
+    builder.exec(increase_allowance_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_OVERFLOW),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_change_allowance_of_frozen_account() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let frozen = Key::Account(*ACCOUNT_1_ADDR);
+    let allowance_amount = U256::from(ALLOWANCE_AMOUNT_1);
+
+    let approve_request =
+        make_erc20_approve_request(owner, &test_contract, frozen, allowance_amount);
+    builder.exec(approve_request).expect_success().commit();
+
+    let freeze_request =
+        make_erc20_freeze_request(*DEFAULT_ACCOUNT_ADDR, &test_contract, METHOD_FREEZE, frozen);
+    builder.exec(freeze_request).expect_success().commit();
+
+    for method in [METHOD_INCREASE_ALLOWANCE, METHOD_DECREASE_ALLOWANCE].iter() {
+        let allowance_request = make_erc20_request(
+            *DEFAULT_ACCOUNT_ADDR,
+            &test_contract,
+            method,
+            runtime_args! {
+                ARG_SPENDER => frozen,
+                ARG_AMOUNT => allowance_amount,
+            },
+        );
+        builder.exec(allowance_request).commit();
+
+        let error = builder.get_error().expect("should have error");
+        assert!(
+            matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_ACCOUNT_FROZEN),
+            "{:?}",
+            error
+        );
+    }
+}
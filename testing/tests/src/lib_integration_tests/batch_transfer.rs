@@ -0,0 +1,106 @@
+use super::*;
+
+fn make_erc20_batch_transfer_request(
+    sender: AccountHash,
+    erc20_token: &ContractHash,
+    recipients: Vec<Key>,
+    amounts: Vec<U256>,
+) -> ExecuteRequest {
+    make_erc20_request(
+        sender,
+        erc20_token,
+        METHOD_BATCH_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENTS => recipients,
+            ARG_AMOUNTS => amounts,
+        },
+    )
+}
+
+#[test]
+fn should_batch_transfer() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient_1 = Key::Account(*ACCOUNT_1_ADDR);
+    let recipient_2 = Key::Account(*ACCOUNT_2_ADDR);
+    let amount_1 = U256::from(TRANSFER_AMOUNT_1);
+    let amount_2 = U256::from(TRANSFER_AMOUNT_2);
+
+    let sender_balance = erc20_check_balance_of(&mut builder, &test_contract, sender);
+
+    let batch_transfer_request = make_erc20_batch_transfer_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        vec![recipient_1, recipient_2, recipient_1],
+        vec![amount_1, amount_2, amount_2],
+    );
+    builder
+        .exec(batch_transfer_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, sender),
+        sender_balance - amount_1 - amount_2 - amount_2
+    );
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, recipient_1),
+        amount_1 + amount_2
+    );
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, recipient_2),
+        amount_2
+    );
+}
+
+#[test]
+fn should_not_batch_transfer_partially() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient_1 = Key::Account(*ACCOUNT_1_ADDR);
+    let recipient_2 = Key::Account(*ACCOUNT_2_ADDR);
+    let amount = U256::from(TRANSFER_AMOUNT_1);
+
+    let sender_balance = erc20_check_balance_of(&mut builder, &test_contract, sender);
+
+    let batch_transfer_request = make_erc20_batch_transfer_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        vec![recipient_1, recipient_2],
+        vec![amount],
+    );
+    builder.exec(batch_transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_LENGTH_MISMATCH),
+        "{:?}",
+        error
+    );
+
+    let batch_transfer_request = make_erc20_batch_transfer_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        vec![recipient_1, recipient_2],
+        vec![amount, sender_balance],
+    );
+    builder.exec(batch_transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_BALANCE),
+        "{:?}",
+        error
+    );
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, sender),
+        sender_balance
+    );
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, recipient_1),
+        U256::zero()
+    );
+}
@@ -0,0 +1,99 @@
+use super::*;
+
+#[test]
+fn should_not_transfer_to_or_from_frozen_account() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let frozen = Key::Account(*ACCOUNT_1_ADDR);
+    let transfer_amount = U256::from(TRANSFER_AMOUNT_1);
+
+    let freeze_request =
+        make_erc20_freeze_request(*DEFAULT_ACCOUNT_ADDR, &test_contract, METHOD_FREEZE, frozen);
+    builder.exec(freeze_request).expect_success().commit();
+
+    let transfer_request =
+        make_erc20_transfer_request(sender, &test_contract, frozen, transfer_amount);
+    builder.exec(transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_ACCOUNT_FROZEN),
+        "{:?}",
+        error
+    );
+
+    let unfreeze_request = make_erc20_freeze_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_UNFREEZE,
+        frozen,
+    );
+    builder.exec(unfreeze_request).expect_success().commit();
+
+    let transfer_request =
+        make_erc20_transfer_request(sender, &test_contract, frozen, transfer_amount);
+    builder.exec(transfer_request).expect_success().commit();
+
+    let freeze_request =
+        make_erc20_freeze_request(*DEFAULT_ACCOUNT_ADDR, &test_contract, METHOD_FREEZE, frozen);
+    builder.exec(freeze_request).expect_success().commit();
+
+    let transfer_request =
+        make_erc20_transfer_request(frozen, &test_contract, sender, transfer_amount);
+    builder.exec(transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_ACCOUNT_FROZEN),
+        "{:?}",
+        error
+    );
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, frozen),
+        transfer_amount
+    );
+}
+
+#[test]
+fn should_not_mint_to_frozen_contract() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let frozen = TOKEN_OWNER_ADDRESS_2;
+
+    let freeze_request =
+        make_erc20_freeze_request(*DEFAULT_ACCOUNT_ADDR, &test_contract, METHOD_FREEZE, frozen);
+    builder.exec(freeze_request).expect_success().commit();
+
+    let mint_request =
+        make_erc20_mint_request(*DEFAULT_ACCOUNT_ADDR, &test_contract, frozen, U256::one());
+    builder.exec(mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_ACCOUNT_FROZEN),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_only_allow_admin_to_freeze() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let freeze_request = make_erc20_freeze_request(
+        *ACCOUNT_1_ADDR,
+        &test_contract,
+        METHOD_FREEZE,
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+    );
+    builder.exec(freeze_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_MISSING_ROLE),
+        "{:?}",
+        error
+    );
+}
@@ -0,0 +1,341 @@
+use super::*;
+
+const METHOD_MINT_BRIDGED: &str = "mint_bridged";
+const ARG_SOURCE_CHAIN_ID: &str = "source_chain_id";
+const ARG_SOURCE_TX_HASH: &str = "source_tx_hash";
+const BRIDGE_OPERATOR_ROLE: &str = "bridge_operator";
+const BRIDGE_TRANSFERS_KEY: &str = "bridge_transfers";
+const SOURCE_CHAIN_ID: u64 = 1;
+const SOURCE_TX_HASH: [u8; 32] = [7; 32];
+const BRIDGED_AMOUNT: u64 = 500;
+
+fn make_erc20_mint_bridged_request(
+    sender: AccountHash,
+    erc20_token: &ContractHash,
+    recipient: Key,
+    source_chain_id: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        *erc20_token,
+        METHOD_MINT_BRIDGED,
+        runtime_args! {
+            ARG_RECIPIENT => recipient,
+            ARG_AMOUNT => U256::from(BRIDGED_AMOUNT),
+            ARG_SOURCE_CHAIN_ID => source_chain_id,
+            ARG_SOURCE_TX_HASH => Bytes::from(SOURCE_TX_HASH.to_vec()),
+        },
+    )
+    .with_block_time(1_000)
+    .build()
+}
+
+/// Returns the key under which a bridge transfer is recorded.
+fn bridge_transfer_dictionary_item_key(source_chain_id: u64, source_tx_hash: &[u8]) -> String {
+    let mut preimage = source_chain_id.to_bytes().unwrap();
+    preimage.append(&mut Bytes::from(source_tx_hash.to_vec()).to_bytes().unwrap());
+    blake2b256(&preimage)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+#[test]
+fn should_mint_bridged_transfer_once() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let operator = Key::Account(*ACCOUNT_1_ADDR);
+    let recipient = Key::Account(*ACCOUNT_2_ADDR);
+
+    // Installer is not a bridge operator unless granted the role.
+    let mint_bridged_request = make_erc20_mint_bridged_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        recipient,
+        SOURCE_CHAIN_ID,
+    );
+    builder.exec(mint_bridged_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_MISSING_ROLE),
+        "{:?}",
+        error
+    );
+
+    let grant_role_request = make_erc20_role_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_GRANT_ROLE,
+        BRIDGE_OPERATOR_ROLE,
+        operator,
+    );
+    builder.exec(grant_role_request).expect_success().commit();
+
+    let mint_bridged_request = make_erc20_mint_bridged_request(
+        *ACCOUNT_1_ADDR,
+        &test_contract,
+        recipient,
+        SOURCE_CHAIN_ID,
+    );
+    builder.exec(mint_bridged_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, recipient),
+        U256::from(BRIDGED_AMOUNT)
+    );
+
+    // Bridge transfers are recorded as a tuple of the recipient, the amount and the block time.
+    let bridge_transfer: (Key, U256, u64) = erc20_get_dictionary_value(
+        &builder,
+        &test_contract,
+        BRIDGE_TRANSFERS_KEY,
+        &bridge_transfer_dictionary_item_key(SOURCE_CHAIN_ID, &SOURCE_TX_HASH),
+    );
+    assert_eq!(
+        bridge_transfer,
+        (recipient, U256::from(BRIDGED_AMOUNT), 1_000)
+    );
+
+    let mint_bridged_request = make_erc20_mint_bridged_request(
+        *ACCOUNT_1_ADDR,
+        &test_contract,
+        recipient,
+        SOURCE_CHAIN_ID,
+    );
+    builder.exec(mint_bridged_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_BRIDGE_TRANSFER_PROCESSED),
+        "{:?}",
+        error
+    );
+
+    // Same transaction hash on another chain is a different transfer.
+    let mint_bridged_request = make_erc20_mint_bridged_request(
+        *ACCOUNT_1_ADDR,
+        &test_contract,
+        recipient,
+        SOURCE_CHAIN_ID + 1,
+    );
+    builder.exec(mint_bridged_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, recipient),
+        U256::from(BRIDGED_AMOUNT * 2)
+    );
+}
+
+#[test]
+fn should_not_mint_bridged_transfer_while_paused() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let operator = Key::Account(*ACCOUNT_1_ADDR);
+    let recipient = Key::Account(*ACCOUNT_2_ADDR);
+
+    let grant_role_request = make_erc20_role_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_GRANT_ROLE,
+        BRIDGE_OPERATOR_ROLE,
+        operator,
+    );
+    builder.exec(grant_role_request).expect_success().commit();
+
+    let pause_request = make_erc20_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_PAUSE,
+        RuntimeArgs::default(),
+    );
+    builder.exec(pause_request).expect_success().commit();
+
+    let mint_bridged_request = make_erc20_mint_bridged_request(
+        *ACCOUNT_1_ADDR,
+        &test_contract,
+        recipient,
+        SOURCE_CHAIN_ID,
+    );
+    builder.exec(mint_bridged_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_PAUSED),
+        "{:?}",
+        error
+    );
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, recipient),
+        U256::zero()
+    );
+}
+
+const METHOD_REQUEST_BRIDGE_BACK: &str = "request_bridge_back";
+const ARG_DESTINATION_ADDRESS: &str = "destination_address";
+const BRIDGE_REQUESTS_KEY: &str = "bridge_requests";
+const BRIDGE_REQUEST_COUNT_KEY: &str = "bridge_request_count";
+const DESTINATION_ADDRESS: [u8; 20] = [9; 20];
+
+fn make_erc20_set_bridge_limits_request(
+    sender: AccountHash,
+    erc20_token: &ContractHash,
+    destination_chain_id: u64,
+    minimum: U256,
+    maximum: U256,
+) -> ExecuteRequest {
+    make_erc20_request(
+        sender,
+        erc20_token,
+        METHOD_SET_BRIDGE_LIMITS,
+        runtime_args! {
+            ARG_DESTINATION_CHAIN_ID => destination_chain_id,
+            ARG_MINIMUM => minimum,
+            ARG_MAXIMUM => maximum,
+        },
+    )
+}
+
+fn make_erc20_request_bridge_back_request(
+    sender: AccountHash,
+    erc20_token: &ContractHash,
+    amount: U256,
+    destination_chain_id: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        *erc20_token,
+        METHOD_REQUEST_BRIDGE_BACK,
+        runtime_args! {
+            ARG_AMOUNT => amount,
+            ARG_DESTINATION_CHAIN_ID => destination_chain_id,
+            ARG_DESTINATION_ADDRESS => DESTINATION_ADDRESS.to_vec(),
+        },
+    )
+    .with_block_time(2_000)
+    .build()
+}
+
+/// Reads the bridge request stored under `index` as a tuple of its sender and amount, its
+/// destination chain id and address, and its block time.
+fn erc20_get_bridge_request(
+    builder: &InMemoryWasmTestBuilder,
+    erc20_contract_hash: &ContractHash,
+    index: u64,
+) -> ((Key, U256), (u64, Vec<u8>), u64) {
+    erc20_get_dictionary_value(
+        builder,
+        erc20_contract_hash,
+        BRIDGE_REQUESTS_KEY,
+        &index.to_string(),
+    )
+}
+
+#[test]
+fn should_burn_tokens_requested_to_bridge_back() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let amount = U256::from(BRIDGE_MAXIMUM);
+
+    // No chain is supported until the admin sets its limits.
+    let request_bridge_back_request = make_erc20_request_bridge_back_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        amount,
+        DESTINATION_CHAIN_ID,
+    );
+    builder.exec(request_bridge_back_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_UNSUPPORTED_CHAIN),
+        "{:?}",
+        error
+    );
+
+    let set_bridge_limits_request = make_erc20_set_bridge_limits_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        DESTINATION_CHAIN_ID,
+        U256::from(BRIDGE_MINIMUM),
+        U256::from(BRIDGE_MAXIMUM),
+    );
+    builder
+        .exec(set_bridge_limits_request)
+        .expect_success()
+        .commit();
+
+    let sender_balance_before = erc20_check_balance_of(&mut builder, &test_contract, sender);
+    let total_supply_before: U256 = builder.get_value(test_contract, TOTAL_SUPPLY_KEY);
+
+    let request_bridge_back_request = make_erc20_request_bridge_back_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        amount,
+        DESTINATION_CHAIN_ID,
+    );
+    builder
+        .exec(request_bridge_back_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, sender),
+        sender_balance_before - amount
+    );
+    let total_supply: U256 = builder.get_value(test_contract, TOTAL_SUPPLY_KEY);
+    assert_eq!(total_supply, total_supply_before - amount);
+
+    let bridge_request_count: u64 = builder.get_value(test_contract, BRIDGE_REQUEST_COUNT_KEY);
+    assert_eq!(bridge_request_count, 1);
+    assert_eq!(
+        erc20_get_bridge_request(&builder, &test_contract, 0),
+        (
+            (sender, amount),
+            (DESTINATION_CHAIN_ID, DESTINATION_ADDRESS.to_vec()),
+            2_000
+        )
+    );
+
+    for out_of_range_amount in [BRIDGE_MINIMUM - 1, BRIDGE_MAXIMUM + 1].iter() {
+        let request_bridge_back_request = make_erc20_request_bridge_back_request(
+            *DEFAULT_ACCOUNT_ADDR,
+            &test_contract,
+            U256::from(*out_of_range_amount),
+            DESTINATION_CHAIN_ID,
+        );
+        builder.exec(request_bridge_back_request).commit();
+
+        let error = builder.get_error().expect("should have error");
+        assert!(
+            matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_BRIDGE_AMOUNT_OUT_OF_RANGE),
+            "{:?}",
+            error
+        );
+    }
+
+    let bridge_request_count: u64 = builder.get_value(test_contract, BRIDGE_REQUEST_COUNT_KEY);
+    assert_eq!(bridge_request_count, 1);
+}
+
+#[test]
+fn should_only_allow_admin_to_set_bridge_limits() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let set_bridge_limits_request = make_erc20_set_bridge_limits_request(
+        *ACCOUNT_1_ADDR,
+        &test_contract,
+        DESTINATION_CHAIN_ID,
+        U256::from(BRIDGE_MINIMUM),
+        U256::from(BRIDGE_MAXIMUM),
+    );
+    builder.exec(set_bridge_limits_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_MISSING_ROLE),
+        "{:?}",
+        error
+    );
+}
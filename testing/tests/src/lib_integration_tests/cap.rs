@@ -0,0 +1,63 @@
+use super::*;
+
+#[test]
+fn should_not_mint_above_cap() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    let cap: U256 = builder.get_value(test_contract, CAP_KEY);
+    assert_eq!(cap, U256::from(TOKEN_CAP));
+
+    let total_supply = erc20_check_total_supply(&mut builder, &test_contract);
+    let remaining = cap - total_supply;
+
+    let mint_request = make_erc20_mint_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        owner,
+        remaining + U256::one(),
+    );
+    builder.exec(mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_CAP_EXCEEDED),
+        "{:?}",
+        error
+    );
+
+    let mint_request =
+        make_erc20_mint_request(*DEFAULT_ACCOUNT_ADDR, &test_contract, owner, remaining);
+    builder.exec(mint_request).expect_success().commit();
+
+    assert_eq!(erc20_check_total_supply(&mut builder, &test_contract), cap);
+}
+
+#[test]
+fn should_not_mint_above_cap_from_install_session() {
+    let (mut builder, _) = setup();
+
+    // Installer has already minted to the first token owner when it mints to the second one.
+    let remaining = U256::from(TOKEN_CAP - TOKEN_TOTAL_SUPPLY - TOKEN_OWNER_AMOUNT_1);
+
+    let install_request =
+        make_erc20_test_install_request(EVENTS_MODE_NATIVE, remaining + U256::one());
+    builder.exec(install_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_CAP_EXCEEDED),
+        "{:?}",
+        error
+    );
+
+    let install_request = make_erc20_test_install_request(EVENTS_MODE_NATIVE, remaining);
+    builder.exec(install_request).expect_success().commit();
+
+    let test_contract = get_test_contract_hash(&builder);
+    assert_eq!(
+        erc20_check_total_supply(&mut builder, &test_contract),
+        U256::from(TOKEN_CAP)
+    );
+}
@@ -0,0 +1,51 @@
+use super::*;
+
+#[test]
+fn should_record_ces_events() {
+    let mint_amount = U256::one();
+
+    let (mut builder, _) = setup();
+
+    let test_contract = install_erc20_test(&mut builder, EVENTS_MODE_CES);
+
+    let contract = builder
+        .get_contract(test_contract)
+        .expect("should have contract");
+    assert!(contract.named_keys().contains_key(CES_EVENTS_SCHEMA_KEY));
+    assert!(!contract.named_keys().contains_key(EVENTS_KEY));
+
+    let ces_version: String = builder.get_value(test_contract, CES_VERSION_KEY);
+    assert_eq!(ces_version, "0.1.0");
+
+    // Initial supply followed by two mints done by the installer.
+    let events_length_before: u32 = builder.get_value(test_contract, CES_EVENTS_LENGTH_KEY);
+    assert_eq!(events_length_before, 3);
+
+    let mint_request = make_erc20_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => mint_amount,
+        },
+    );
+    builder.exec(mint_request).expect_success().commit();
+
+    let events_length_after: u32 = builder.get_value(test_contract, CES_EVENTS_LENGTH_KEY);
+    assert_eq!(events_length_after, events_length_before + 1);
+
+    let event_bytes: Vec<u8> = erc20_get_dictionary_value(
+        &builder,
+        &test_contract,
+        CES_EVENTS_KEY,
+        &events_length_before.to_string(),
+    );
+    let (event_name, remainder) = String::from_bytes(&event_bytes).unwrap();
+    assert_eq!(event_name, "event_Mint");
+    let (recipient, remainder) = Key::from_bytes(remainder).unwrap();
+    assert_eq!(recipient, TOKEN_OWNER_ADDRESS_1);
+    let (amount, remainder) = U256::from_bytes(remainder).unwrap();
+    assert_eq!(amount, mint_amount);
+    assert!(remainder.is_empty());
+}
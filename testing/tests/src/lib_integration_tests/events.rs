@@ -0,0 +1,163 @@
+use super::*;
+
+#[test]
+fn should_record_events_in_order() {
+    let (mut builder, TestContext { erc20_token, .. }) = setup();
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_1_ADDR);
+    let spender = Key::Account(*ACCOUNT_2_ADDR);
+
+    let transfer_amount = U256::from(TRANSFER_AMOUNT_1);
+    let allowance_amount = U256::from(ALLOWANCE_AMOUNT_1);
+
+    let events_count: u64 = builder.get_value(erc20_token, EVENTS_COUNT_KEY);
+    assert_eq!(events_count, 1);
+
+    let mint_event: BTreeMap<String, String> =
+        erc20_get_dictionary_value(&builder, &erc20_token, EVENTS_KEY, "0");
+    assert_eq!(mint_event["event_type"], "mint");
+    assert_eq!(mint_event["recipient"], owner.to_formatted_string());
+    assert_eq!(mint_event["amount"], TOKEN_TOTAL_SUPPLY.to_string());
+
+    let transfer_request =
+        make_erc20_transfer_request(owner, &erc20_token, recipient, transfer_amount);
+    builder.exec(transfer_request).expect_success().commit();
+
+    let approve_request =
+        make_erc20_approve_request(owner, &erc20_token, spender, allowance_amount);
+    builder.exec(approve_request).expect_success().commit();
+
+    let events_count: u64 = builder.get_value(erc20_token, EVENTS_COUNT_KEY);
+    assert_eq!(events_count, 3);
+
+    let transfer_event: BTreeMap<String, String> =
+        erc20_get_dictionary_value(&builder, &erc20_token, EVENTS_KEY, "1");
+    assert_eq!(transfer_event["event_type"], "transfer");
+    assert_eq!(transfer_event["sender"], owner.to_formatted_string());
+    assert_eq!(transfer_event["recipient"], recipient.to_formatted_string());
+    assert_eq!(transfer_event["amount"], transfer_amount.to_string());
+
+    let approval_event: BTreeMap<String, String> =
+        erc20_get_dictionary_value(&builder, &erc20_token, EVENTS_KEY, "2");
+    assert_eq!(approval_event["event_type"], "approval");
+    assert_eq!(approval_event["owner"], owner.to_formatted_string());
+    assert_eq!(approval_event["spender"], spender.to_formatted_string());
+    assert_eq!(approval_event["amount"], allowance_amount.to_string());
+}
+
+#[test]
+fn should_record_mint_and_burn_events() {
+    let mint_amount = U256::one();
+
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    // Initial supply followed by two mints done by the installer.
+    let events_count_before: u64 = builder.get_value(test_contract, EVENTS_COUNT_KEY);
+    assert_eq!(events_count_before, 3);
+
+    let mint_request = make_erc20_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => mint_amount,
+        },
+    );
+    builder.exec(mint_request).expect_success().commit();
+
+    let burn_request = make_erc20_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_BURN,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => mint_amount,
+        },
+    );
+    builder.exec(burn_request).expect_success().commit();
+
+    let events_count_after: u64 = builder.get_value(test_contract, EVENTS_COUNT_KEY);
+    assert_eq!(events_count_after, events_count_before + 2);
+
+    let mint_event: BTreeMap<String, String> = erc20_get_dictionary_value(
+        &builder,
+        &test_contract,
+        EVENTS_KEY,
+        &events_count_before.to_string(),
+    );
+    assert_eq!(mint_event["event_type"], "mint");
+    assert_eq!(
+        mint_event["recipient"],
+        TOKEN_OWNER_ADDRESS_1.to_formatted_string()
+    );
+    assert_eq!(mint_event["amount"], mint_amount.to_string());
+
+    let burn_event: BTreeMap<String, String> = erc20_get_dictionary_value(
+        &builder,
+        &test_contract,
+        EVENTS_KEY,
+        &(events_count_before + 1).to_string(),
+    );
+    assert_eq!(burn_event["event_type"], "burn");
+    assert_eq!(
+        burn_event["owner"],
+        TOKEN_OWNER_ADDRESS_1.to_formatted_string()
+    );
+    assert_eq!(burn_event["amount"], mint_amount.to_string());
+}
+
+#[test]
+fn should_mint_from_install_session_in_every_events_mode() {
+    let (mut builder, _) = setup();
+
+    for events_mode in [EVENTS_MODE_NO_EVENTS, EVENTS_MODE_NATIVE, EVENTS_MODE_CES] {
+        // Installer mints to both of the token owners before its session ends.
+        let test_contract = install_erc20_test(&mut builder, events_mode);
+
+        let owner_1_balance =
+            erc20_check_balance_of(&mut builder, &test_contract, TOKEN_OWNER_ADDRESS_1);
+        assert_eq!(owner_1_balance, U256::from(TOKEN_OWNER_AMOUNT_1));
+        let owner_2_balance =
+            erc20_check_balance_of(&mut builder, &test_contract, TOKEN_OWNER_ADDRESS_2);
+        assert_eq!(owner_2_balance, U256::from(TOKEN_OWNER_AMOUNT_2));
+
+        match events_mode {
+            EVENTS_MODE_NATIVE => {
+                let events_count: u64 = builder.get_value(test_contract, EVENTS_COUNT_KEY);
+                assert_eq!(events_count, 3);
+
+                let mint_event: BTreeMap<String, String> =
+                    erc20_get_dictionary_value(&builder, &test_contract, EVENTS_KEY, "2");
+                assert_eq!(mint_event["event_type"], "mint");
+                assert_eq!(
+                    mint_event["recipient"],
+                    TOKEN_OWNER_ADDRESS_2.to_formatted_string()
+                );
+                assert_eq!(mint_event["amount"], TOKEN_OWNER_AMOUNT_2.to_string());
+            }
+            EVENTS_MODE_CES => {
+                let events_length: u32 = builder.get_value(test_contract, CES_EVENTS_LENGTH_KEY);
+                assert_eq!(events_length, 3);
+
+                let event_bytes: Vec<u8> =
+                    erc20_get_dictionary_value(&builder, &test_contract, CES_EVENTS_KEY, "2");
+                let (event_name, remainder) = String::from_bytes(&event_bytes).unwrap();
+                assert_eq!(event_name, "event_Mint");
+                let (recipient, remainder) = Key::from_bytes(remainder).unwrap();
+                assert_eq!(recipient, TOKEN_OWNER_ADDRESS_2);
+                let (amount, remainder) = U256::from_bytes(remainder).unwrap();
+                assert_eq!(amount, U256::from(TOKEN_OWNER_AMOUNT_2));
+                assert!(remainder.is_empty());
+            }
+            _ => {
+                let contract = builder
+                    .get_contract(test_contract)
+                    .expect("should have contract");
+                assert!(!contract.named_keys().contains_key(EVENTS_KEY));
+                assert!(!contract.named_keys().contains_key(CES_EVENTS_KEY));
+            }
+        }
+    }
+}
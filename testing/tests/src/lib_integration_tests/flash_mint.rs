@@ -0,0 +1,118 @@
+use super::*;
+
+fn make_erc20_flash_loan_request(
+    sender: AccountHash,
+    erc20_token: &ContractHash,
+    receiver: Key,
+    amount: U256,
+    data: &[u8],
+) -> ExecuteRequest {
+    make_erc20_request(
+        sender,
+        erc20_token,
+        METHOD_FLASH_LOAN,
+        runtime_args! {
+            ARG_RECEIVER => receiver,
+            ARG_AMOUNT => amount,
+            ARG_DATA => Bytes::from(data.to_vec()),
+        },
+    )
+}
+
+#[test]
+fn should_flash_loan_and_burn_repayment() {
+    let (
+        mut builder,
+        TestContext {
+            test_contract,
+            erc20_test_call,
+            ..
+        },
+    ) = setup();
+
+    let initiator = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let borrower = Key::Hash(erc20_test_call.value());
+    let loan_amount = U256::from(TOKEN_OWNER_AMOUNT_1);
+    let fee = loan_amount * TOKEN_FLASH_FEE_BASIS_POINTS / 10_000;
+    let borrower_balance = U256::from(TRANSFER_AMOUNT_2);
+
+    // Borrower needs some tokens of its own to pay the fee.
+    let transfer_request =
+        make_erc20_transfer_request(initiator, &test_contract, borrower, borrower_balance);
+    builder.exec(transfer_request).expect_success().commit();
+
+    let total_supply = erc20_check_total_supply(&mut builder, &test_contract);
+
+    let flash_loan_request = make_erc20_flash_loan_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        borrower,
+        loan_amount,
+        b"",
+    );
+    builder.exec(flash_loan_request).expect_success().commit();
+
+    let loan: (Key, U256, U256) = get_test_result(&mut builder, erc20_test_call);
+    assert_eq!(loan, (initiator, loan_amount, fee));
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, borrower),
+        borrower_balance - fee
+    );
+    assert_eq!(
+        erc20_check_total_supply(&mut builder, &test_contract),
+        total_supply - fee
+    );
+}
+
+#[test]
+fn should_not_flash_loan_without_repayment() {
+    let (
+        mut builder,
+        TestContext {
+            test_contract,
+            erc20_test_call,
+            ..
+        },
+    ) = setup();
+
+    let borrower = Key::Hash(erc20_test_call.value());
+    let loan_amount = U256::from(TOKEN_OWNER_AMOUNT_1);
+
+    let flash_loan_request = make_erc20_flash_loan_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        borrower,
+        loan_amount,
+        KEEP_DATA,
+    );
+    builder.exec(flash_loan_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_ALLOWANCE),
+        "{:?}",
+        error
+    );
+
+    let flash_loan_request = make_erc20_flash_loan_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        borrower,
+        loan_amount,
+        REJECT_DATA,
+    );
+    builder.exec(flash_loan_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_RECEIVER_REJECTED),
+        "{:?}",
+        error
+    );
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, borrower),
+        U256::zero()
+    );
+}
@@ -0,0 +1,103 @@
+use super::*;
+
+const ARG_OFFSET: &str = "offset";
+const ARG_LIMIT: &str = "limit";
+
+fn erc20_check_holder_count(
+    builder: &mut InMemoryWasmTestBuilder,
+    erc20_contract_hash: &ContractHash,
+    erc20_test_call: ContractPackageHash,
+) -> u64 {
+    let exec_request = make_erc20_check_at_request(
+        CHECK_HOLDER_COUNT_ENTRYPOINT,
+        erc20_test_call,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => *erc20_contract_hash,
+        },
+    );
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, erc20_test_call)
+}
+
+fn erc20_check_holders(
+    builder: &mut InMemoryWasmTestBuilder,
+    erc20_contract_hash: &ContractHash,
+    erc20_test_call: ContractPackageHash,
+    offset: u64,
+    limit: u64,
+) -> Vec<Key> {
+    let exec_request = make_erc20_check_at_request(
+        CHECK_HOLDERS_ENTRYPOINT,
+        erc20_test_call,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => *erc20_contract_hash,
+            ARG_OFFSET => offset,
+            ARG_LIMIT => limit,
+        },
+    );
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, erc20_test_call)
+}
+
+#[test]
+fn should_enumerate_holders() {
+    let (
+        mut builder,
+        TestContext {
+            test_contract,
+            erc20_test_call,
+            ..
+        },
+    ) = setup();
+
+    let installer = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_1_ADDR);
+
+    // Installer and both owners minted to at install time are indexed in order.
+    assert_eq!(
+        erc20_check_holder_count(&mut builder, &test_contract, erc20_test_call),
+        3
+    );
+    assert_eq!(
+        erc20_check_holders(&mut builder, &test_contract, erc20_test_call, 0, 10),
+        vec![installer, TOKEN_OWNER_ADDRESS_1, TOKEN_OWNER_ADDRESS_2]
+    );
+
+    let transfer_request = make_erc20_transfer_request(
+        installer,
+        &test_contract,
+        recipient,
+        U256::from(TRANSFER_AMOUNT_1),
+    );
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_holder_count(&mut builder, &test_contract, erc20_test_call),
+        4
+    );
+    assert_eq!(
+        erc20_check_holders(&mut builder, &test_contract, erc20_test_call, 2, 10),
+        vec![TOKEN_OWNER_ADDRESS_2, recipient]
+    );
+    assert_eq!(
+        erc20_check_holders(&mut builder, &test_contract, erc20_test_call, 4, 10),
+        Vec::<Key>::new()
+    );
+
+    // Emptying a balance moves the last holder into the place of the removed one.
+    let installer_balance = erc20_check_balance_of(&mut builder, &test_contract, installer);
+    let transfer_request =
+        make_erc20_transfer_request(installer, &test_contract, recipient, installer_balance);
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_holder_count(&mut builder, &test_contract, erc20_test_call),
+        3
+    );
+    assert_eq!(
+        erc20_check_holders(&mut builder, &test_contract, erc20_test_call, 0, 2),
+        vec![recipient, TOKEN_OWNER_ADDRESS_1]
+    );
+}
@@ -0,0 +1,102 @@
+use super::*;
+
+const METADATA_KEY: &str = "metadata";
+const METADATA_VERSION_KEY: &str = "metadata_version";
+const METADATA_ICON_URL: &str = "icon_url";
+const TOKEN_ICON_URL: &str = "https://example.com/csprt.svg";
+
+fn make_erc20_set_metadata_request(
+    sender: AccountHash,
+    erc20_token: &ContractHash,
+    key: &str,
+    value: &str,
+) -> ExecuteRequest {
+    make_erc20_request(
+        sender,
+        erc20_token,
+        METHOD_SET_METADATA,
+        runtime_args! {
+            ARG_KEY => key.to_string(),
+            ARG_VALUE => value.to_string(),
+        },
+    )
+}
+
+fn make_metadata(entries: &[(&str, &str)]) -> BTreeMap<String, String> {
+    entries
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn should_version_metadata_changes() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let metadata: BTreeMap<String, String> = builder.get_value(test_contract, METADATA_KEY);
+    let metadata_version: u64 = builder.get_value(test_contract, METADATA_VERSION_KEY);
+    assert_eq!(
+        metadata,
+        make_metadata(&[(METADATA_ICON_URL, TOKEN_ICON_URL)])
+    );
+    assert_eq!(metadata_version, 0);
+
+    let set_metadata_request = make_erc20_set_metadata_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METADATA_WEBSITE_URL,
+        TOKEN_WEBSITE_URL,
+    );
+    builder.exec(set_metadata_request).expect_success().commit();
+
+    let metadata: BTreeMap<String, String> = builder.get_value(test_contract, METADATA_KEY);
+    let metadata_version: u64 = builder.get_value(test_contract, METADATA_VERSION_KEY);
+    assert_eq!(
+        metadata,
+        make_metadata(&[
+            (METADATA_ICON_URL, TOKEN_ICON_URL),
+            (METADATA_WEBSITE_URL, TOKEN_WEBSITE_URL),
+        ])
+    );
+    assert_eq!(metadata_version, 1);
+
+    // Empty value removes the entry.
+    let set_metadata_request = make_erc20_set_metadata_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METADATA_ICON_URL,
+        "",
+    );
+    builder.exec(set_metadata_request).expect_success().commit();
+
+    let metadata: BTreeMap<String, String> = builder.get_value(test_contract, METADATA_KEY);
+    let metadata_version: u64 = builder.get_value(test_contract, METADATA_VERSION_KEY);
+    assert_eq!(
+        metadata,
+        make_metadata(&[(METADATA_WEBSITE_URL, TOKEN_WEBSITE_URL)])
+    );
+    assert_eq!(metadata_version, 2);
+}
+
+#[test]
+fn should_only_allow_admin_to_set_metadata() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let set_metadata_request = make_erc20_set_metadata_request(
+        *ACCOUNT_1_ADDR,
+        &test_contract,
+        METADATA_WEBSITE_URL,
+        TOKEN_WEBSITE_URL,
+    );
+    builder.exec(set_metadata_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_MISSING_ROLE),
+        "{:?}",
+        error
+    );
+
+    let metadata_version: u64 = builder.get_value(test_contract, METADATA_VERSION_KEY);
+    assert_eq!(metadata_version, 0);
+}
@@ -0,0 +1,139 @@
+use super::*;
+
+#[test]
+fn should_limit_minting_to_minter_allowance() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let minter = Key::Account(*ACCOUNT_1_ADDR);
+    let recipient = Key::Account(*ACCOUNT_2_ADDR);
+
+    let configure_minter_request = make_erc20_configure_minter_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        minter,
+        U256::from(MINTER_ALLOWANCE),
+    );
+    builder
+        .exec(configure_minter_request)
+        .expect_success()
+        .commit();
+
+    let mint_request = make_erc20_mint_request(
+        *ACCOUNT_1_ADDR,
+        &test_contract,
+        recipient,
+        U256::from(MINTER_ALLOWANCE - 40),
+    );
+    builder.exec(mint_request).expect_success().commit();
+
+    // Only 40 tokens are left of the allowance.
+    let mint_request =
+        make_erc20_mint_request(*ACCOUNT_1_ADDR, &test_contract, recipient, U256::from(41));
+    builder.exec(mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_MINTER_ALLOWANCE_EXCEEDED),
+        "{:?}",
+        error
+    );
+
+    let mint_request =
+        make_erc20_mint_request(*ACCOUNT_1_ADDR, &test_contract, recipient, U256::from(40));
+    builder.exec(mint_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, recipient),
+        U256::from(MINTER_ALLOWANCE)
+    );
+
+    let mint_request =
+        make_erc20_mint_request(*ACCOUNT_1_ADDR, &test_contract, recipient, U256::one());
+    builder.exec(mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_MINTER_ALLOWANCE_EXCEEDED),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_remove_minter() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let minter = Key::Account(*ACCOUNT_1_ADDR);
+
+    let configure_minter_request = make_erc20_configure_minter_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        minter,
+        U256::from(MINTER_ALLOWANCE),
+    );
+    builder
+        .exec(configure_minter_request)
+        .expect_success()
+        .commit();
+
+    // Only admins configure minters.
+    let configure_minter_request = make_erc20_configure_minter_request(
+        *ACCOUNT_1_ADDR,
+        &test_contract,
+        minter,
+        U256::from(MINTER_ALLOWANCE * 2),
+    );
+    builder.exec(configure_minter_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_MISSING_ROLE),
+        "{:?}",
+        error
+    );
+
+    let remove_minter_request = make_erc20_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_REMOVE_MINTER,
+        runtime_args! {
+            ARG_MINTER => minter,
+        },
+    );
+    builder
+        .exec(remove_minter_request)
+        .expect_success()
+        .commit();
+
+    let mint_request =
+        make_erc20_mint_request(*ACCOUNT_1_ADDR, &test_contract, minter, U256::one());
+    builder.exec(mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_MISSING_ROLE),
+        "{:?}",
+        error
+    );
+
+    // Granting the role again does not bring the allowance back.
+    let grant_role_request = make_erc20_role_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_GRANT_ROLE,
+        MINTER_ROLE,
+        minter,
+    );
+    builder.exec(grant_role_request).expect_success().commit();
+
+    let mint_request =
+        make_erc20_mint_request(*ACCOUNT_1_ADDR, &test_contract, minter, U256::one());
+    builder.exec(mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_MINTER_ALLOWANCE_EXCEEDED),
+        "{:?}",
+        error
+    );
+}
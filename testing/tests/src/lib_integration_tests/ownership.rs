@@ -0,0 +1,299 @@
+use super::*;
+
+const METHOD_TRANSFER_OWNERSHIP: &str = "transfer_ownership";
+const METHOD_ACCEPT_OWNERSHIP: &str = "accept_ownership";
+const METHOD_RENOUNCE_OWNERSHIP: &str = "renounce_ownership";
+const ARG_NEW_OWNER: &str = "new_owner";
+const OWNER_KEY: &str = "owner";
+const PENDING_OWNER_KEY: &str = "pending_owner";
+
+fn assert_missing_role_error(builder: &InMemoryWasmTestBuilder) {
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_MISSING_ROLE),
+        "{:?}",
+        error
+    );
+}
+
+fn assert_not_owner_error(builder: &InMemoryWasmTestBuilder) {
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_NOT_OWNER),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_transfer_ownership_in_two_steps() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let installer = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let new_owner = Key::Account(*ACCOUNT_1_ADDR);
+
+    let owner: Option<Key> = builder.get_value(test_contract, OWNER_KEY);
+    assert_eq!(owner, Some(installer));
+
+    let transfer_ownership_request = make_erc20_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_TRANSFER_OWNERSHIP,
+        runtime_args! {
+            ARG_NEW_OWNER => new_owner,
+        },
+    );
+    builder
+        .exec(transfer_ownership_request)
+        .expect_success()
+        .commit();
+
+    // Nomination alone does not hand over the ownership.
+    let owner: Option<Key> = builder.get_value(test_contract, OWNER_KEY);
+    let pending_owner: Option<Key> = builder.get_value(test_contract, PENDING_OWNER_KEY);
+    assert_eq!(owner, Some(installer));
+    assert_eq!(pending_owner, Some(new_owner));
+
+    let accept_ownership_request = make_erc20_request(
+        *ACCOUNT_2_ADDR,
+        &test_contract,
+        METHOD_ACCEPT_OWNERSHIP,
+        RuntimeArgs::default(),
+    );
+    builder.exec(accept_ownership_request).commit();
+    assert_not_owner_error(&builder);
+
+    let accept_ownership_request = make_erc20_request(
+        *ACCOUNT_1_ADDR,
+        &test_contract,
+        METHOD_ACCEPT_OWNERSHIP,
+        RuntimeArgs::default(),
+    );
+    builder
+        .exec(accept_ownership_request)
+        .expect_success()
+        .commit();
+
+    let owner: Option<Key> = builder.get_value(test_contract, OWNER_KEY);
+    let pending_owner: Option<Key> = builder.get_value(test_contract, PENDING_OWNER_KEY);
+    assert_eq!(owner, Some(new_owner));
+    assert_eq!(pending_owner, None);
+
+    // Previous owner lost its rights, including the admin role.
+    let transfer_ownership_request = make_erc20_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_TRANSFER_OWNERSHIP,
+        runtime_args! {
+            ARG_NEW_OWNER => installer,
+        },
+    );
+    builder.exec(transfer_ownership_request).commit();
+    assert_not_owner_error(&builder);
+
+    let pause_request = make_erc20_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_PAUSE,
+        RuntimeArgs::default(),
+    );
+    builder.exec(pause_request).commit();
+    assert_missing_role_error(&builder);
+
+    let pause_request = make_erc20_request(
+        *ACCOUNT_1_ADDR,
+        &test_contract,
+        METHOD_PAUSE,
+        RuntimeArgs::default(),
+    );
+    builder.exec(pause_request).expect_success().commit();
+}
+
+#[test]
+fn should_renounce_ownership() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let renounce_ownership_request = make_erc20_request(
+        *ACCOUNT_1_ADDR,
+        &test_contract,
+        METHOD_RENOUNCE_OWNERSHIP,
+        RuntimeArgs::default(),
+    );
+    builder.exec(renounce_ownership_request).commit();
+    assert_not_owner_error(&builder);
+
+    let renounce_ownership_request = make_erc20_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_RENOUNCE_OWNERSHIP,
+        RuntimeArgs::default(),
+    );
+    builder
+        .exec(renounce_ownership_request)
+        .expect_success()
+        .commit();
+
+    let owner: Option<Key> = builder.get_value(test_contract, OWNER_KEY);
+    assert_eq!(owner, None);
+
+    let transfer_ownership_request = make_erc20_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_TRANSFER_OWNERSHIP,
+        runtime_args! {
+            ARG_NEW_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        },
+    );
+    builder.exec(transfer_ownership_request).commit();
+    assert_not_owner_error(&builder);
+
+    let pause_request = make_erc20_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_PAUSE,
+        RuntimeArgs::default(),
+    );
+    builder.exec(pause_request).commit();
+    assert_missing_role_error(&builder);
+}
+
+/// Makes a call to every entry point of the test contract which is guarded by the admin role.
+fn make_erc20_admin_requests(
+    sender: AccountHash,
+    erc20_token: &ContractHash,
+) -> Vec<ExecuteRequest> {
+    let address = Key::Account(*ACCOUNT_2_ADDR);
+    let calls = vec![
+        (METHOD_SNAPSHOT, RuntimeArgs::default()),
+        (
+            METHOD_GRANT_ROLE,
+            runtime_args! {
+                ARG_ROLE => MINTER_ROLE,
+                ARG_ADDRESS => address,
+            },
+        ),
+        (
+            METHOD_REVOKE_ROLE,
+            runtime_args! {
+                ARG_ROLE => MINTER_ROLE,
+                ARG_ADDRESS => address,
+            },
+        ),
+        (
+            METHOD_CONFIGURE_MINTER,
+            runtime_args! {
+                ARG_MINTER => address,
+                ARG_ALLOWANCE => U256::from(MINTER_ALLOWANCE),
+            },
+        ),
+        (
+            METHOD_REMOVE_MINTER,
+            runtime_args! {
+                ARG_MINTER => address,
+            },
+        ),
+        (METHOD_PAUSE, RuntimeArgs::default()),
+        (METHOD_UNPAUSE, RuntimeArgs::default()),
+        (
+            METHOD_FREEZE,
+            runtime_args! {
+                ARG_ADDRESS => address,
+            },
+        ),
+        (
+            METHOD_UNFREEZE,
+            runtime_args! {
+                ARG_ADDRESS => address,
+            },
+        ),
+        (
+            METHOD_SET_TRANSFER_FEE,
+            runtime_args! {
+                ARG_BASIS_POINTS => TRANSFER_FEE_BASIS_POINTS,
+                ARG_MINIMUM => U256::from(TRANSFER_FEE_MINIMUM),
+            },
+        ),
+        (
+            METHOD_SET_FEE_EXEMPT,
+            runtime_args! {
+                ARG_ADDRESS => address,
+                ARG_EXEMPT => true,
+            },
+        ),
+        (
+            METHOD_SET_METADATA,
+            runtime_args! {
+                ARG_KEY => METADATA_WEBSITE_URL.to_string(),
+                ARG_VALUE => TOKEN_WEBSITE_URL.to_string(),
+            },
+        ),
+        (
+            METHOD_SET_BRIDGE_LIMITS,
+            runtime_args! {
+                ARG_DESTINATION_CHAIN_ID => DESTINATION_CHAIN_ID,
+                ARG_MINIMUM => U256::from(BRIDGE_MINIMUM),
+                ARG_MAXIMUM => U256::from(BRIDGE_MAXIMUM),
+            },
+        ),
+    ];
+    calls
+        .into_iter()
+        .map(|(method, args)| make_erc20_request(sender, erc20_token, method, args))
+        .collect()
+}
+
+#[test]
+fn should_revoke_admin_entry_points_from_former_owner() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let new_owner = Key::Account(*ACCOUNT_1_ADDR);
+
+    let transfer_ownership_request = make_erc20_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_TRANSFER_OWNERSHIP,
+        runtime_args! {
+            ARG_NEW_OWNER => new_owner,
+        },
+    );
+    builder
+        .exec(transfer_ownership_request)
+        .expect_success()
+        .commit();
+
+    let accept_ownership_request = make_erc20_request(
+        *ACCOUNT_1_ADDR,
+        &test_contract,
+        METHOD_ACCEPT_OWNERSHIP,
+        RuntimeArgs::default(),
+    );
+    builder
+        .exec(accept_ownership_request)
+        .expect_success()
+        .commit();
+
+    for admin_request in make_erc20_admin_requests(*DEFAULT_ACCOUNT_ADDR, &test_contract) {
+        builder.exec(admin_request).commit();
+        assert_missing_role_error(&builder);
+    }
+
+    for admin_request in make_erc20_admin_requests(*ACCOUNT_1_ADDR, &test_contract) {
+        builder.exec(admin_request).expect_success().commit();
+    }
+
+    let renounce_ownership_request = make_erc20_request(
+        *ACCOUNT_1_ADDR,
+        &test_contract,
+        METHOD_RENOUNCE_OWNERSHIP,
+        RuntimeArgs::default(),
+    );
+    builder
+        .exec(renounce_ownership_request)
+        .expect_success()
+        .commit();
+
+    for admin_request in make_erc20_admin_requests(*ACCOUNT_1_ADDR, &test_contract) {
+        builder.exec(admin_request).commit();
+        assert_missing_role_error(&builder);
+    }
+}
@@ -0,0 +1,75 @@
+use super::*;
+
+#[test]
+fn should_not_transfer_while_paused() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_1_ADDR);
+    let transfer_amount = U256::from(TRANSFER_AMOUNT_1);
+
+    let paused: bool = builder.get_value(test_contract, PAUSED_KEY);
+    assert!(!paused);
+
+    let pause_request = make_erc20_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_PAUSE,
+        RuntimeArgs::default(),
+    );
+    builder.exec(pause_request).expect_success().commit();
+
+    let paused: bool = builder.get_value(test_contract, PAUSED_KEY);
+    assert!(paused);
+
+    let transfer_request =
+        make_erc20_transfer_request(sender, &test_contract, recipient, transfer_amount);
+    builder.exec(transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_PAUSED),
+        "{:?}",
+        error
+    );
+
+    let unpause_request = make_erc20_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_UNPAUSE,
+        RuntimeArgs::default(),
+    );
+    builder.exec(unpause_request).expect_success().commit();
+
+    let transfer_request =
+        make_erc20_transfer_request(sender, &test_contract, recipient, transfer_amount);
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, recipient),
+        transfer_amount
+    );
+}
+
+#[test]
+fn should_only_allow_admin_to_pause() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let pause_request = make_erc20_request(
+        *ACCOUNT_1_ADDR,
+        &test_contract,
+        METHOD_PAUSE,
+        RuntimeArgs::default(),
+    );
+    builder.exec(pause_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_MISSING_ROLE),
+        "{:?}",
+        error
+    );
+
+    let paused: bool = builder.get_value(test_contract, PAUSED_KEY);
+    assert!(!paused);
+}
@@ -0,0 +1,179 @@
+use super::*;
+
+/// An approval signed off-chain by the owner of the tokens.
+struct Permit {
+    owner: PublicKey,
+    spender: Key,
+    amount: U256,
+    deadline: u64,
+    nonce: u64,
+}
+
+impl Permit {
+    /// Signs the permit digest for a token contract the same way a wallet would.
+    fn sign(
+        &self,
+        builder: &InMemoryWasmTestBuilder,
+        erc20_token: &ContractHash,
+        secret_key: &SecretKey,
+    ) -> Vec<u8> {
+        let contract_package_hash = builder
+            .get_contract(*erc20_token)
+            .expect("should have contract")
+            .contract_package_hash();
+
+        let domain_separator = {
+            let mut preimage = Vec::new();
+            preimage.append(&mut PERMIT_DOMAIN.to_string().to_bytes().unwrap());
+            preimage.append(&mut TOKEN_NAME.to_string().to_bytes().unwrap());
+            preimage.append(&mut contract_package_hash.to_bytes().unwrap());
+            blake2b256(&preimage)
+        };
+
+        let owner = Key::Account(self.owner.to_account_hash());
+
+        let mut preimage = domain_separator.to_vec();
+        preimage.append(&mut owner.to_bytes().unwrap());
+        preimage.append(&mut self.spender.to_bytes().unwrap());
+        preimage.append(&mut self.amount.to_bytes().unwrap());
+        preimage.append(&mut self.nonce.to_bytes().unwrap());
+        preimage.append(&mut self.deadline.to_bytes().unwrap());
+        let digest = blake2b256(&preimage);
+
+        let signature = crypto::sign(digest, secret_key, &self.owner);
+        signature.to_bytes().unwrap()
+    }
+
+    fn make_request(
+        &self,
+        sender: AccountHash,
+        erc20_token: &ContractHash,
+        signature: Vec<u8>,
+    ) -> ExecuteRequestBuilder {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            sender,
+            *erc20_token,
+            METHOD_PERMIT,
+            runtime_args! {
+                ARG_OWNER => self.owner.clone(),
+                ARG_SPENDER => self.spender,
+                ARG_AMOUNT => self.amount,
+                ARG_DEADLINE => self.deadline,
+                ARG_NONCE => self.nonce,
+                ARG_SIGNATURE => signature,
+            },
+        )
+    }
+}
+
+#[test]
+fn should_approve_with_permit() {
+    let (mut builder, TestContext { erc20_token, .. }) = setup();
+
+    let owner = Key::Account(*ACCOUNT_1_ADDR);
+    let spender = Key::Account(*ACCOUNT_2_ADDR);
+
+    let permit = Permit {
+        owner: ACCOUNT_1_PUBLIC_KEY.clone(),
+        spender,
+        amount: U256::from(ALLOWANCE_AMOUNT_1),
+        deadline: u64::MAX,
+        nonce: 0,
+    };
+    let signature = permit.sign(&builder, &erc20_token, &*ACCOUNT_1_SECRET_KEY);
+
+    // Permit is submitted by the spender rather than the owner.
+    let permit_request = permit
+        .make_request(*ACCOUNT_2_ADDR, &erc20_token, signature.clone())
+        .build();
+    builder.exec(permit_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_allowance_of(&mut builder, owner, spender),
+        permit.amount
+    );
+
+    let nonce: u64 = erc20_get_dictionary_value(
+        &builder,
+        &erc20_token,
+        NONCES_KEY,
+        &base64::encode(&owner.to_bytes().unwrap()),
+    );
+    assert_eq!(nonce, 1);
+
+    // The very same signature can't be used twice.
+    let replayed_permit_request = permit
+        .make_request(*ACCOUNT_2_ADDR, &erc20_token, signature)
+        .build();
+    builder.exec(replayed_permit_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_NONCE),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_approve_with_permit_signed_by_someone_else() {
+    let (mut builder, TestContext { erc20_token, .. }) = setup();
+
+    let owner = Key::Account(*ACCOUNT_1_ADDR);
+    let spender = Key::Account(*ACCOUNT_2_ADDR);
+
+    let permit = Permit {
+        owner: ACCOUNT_1_PUBLIC_KEY.clone(),
+        spender,
+        amount: U256::from(ALLOWANCE_AMOUNT_1),
+        deadline: u64::MAX,
+        nonce: 0,
+    };
+    let signature = permit.sign(&builder, &erc20_token, &*ACCOUNT_2_SECRET_KEY);
+
+    let permit_request = permit
+        .make_request(*ACCOUNT_2_ADDR, &erc20_token, signature)
+        .build();
+    builder.exec(permit_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_SIGNATURE),
+        "{:?}",
+        error
+    );
+
+    assert_eq!(
+        erc20_check_allowance_of(&mut builder, owner, spender),
+        U256::zero()
+    );
+}
+
+#[test]
+fn should_not_approve_with_expired_permit() {
+    let deadline = 1_000;
+
+    let (mut builder, TestContext { erc20_token, .. }) = setup();
+
+    let permit = Permit {
+        owner: ACCOUNT_1_PUBLIC_KEY.clone(),
+        spender: Key::Account(*ACCOUNT_2_ADDR),
+        amount: U256::from(ALLOWANCE_AMOUNT_1),
+        deadline,
+        nonce: 0,
+    };
+    let signature = permit.sign(&builder, &erc20_token, &*ACCOUNT_1_SECRET_KEY);
+
+    let permit_request = permit
+        .make_request(*ACCOUNT_2_ADDR, &erc20_token, signature)
+        .with_block_time(deadline + 1)
+        .build();
+    builder.exec(permit_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_PERMIT_EXPIRED),
+        "{:?}",
+        error
+    );
+}
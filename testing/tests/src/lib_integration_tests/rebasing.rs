@@ -0,0 +1,288 @@
+use super::*;
+
+const REBASING_TEST_CONTRACT_KEY: &str = "rebasing_test_contract";
+const METHOD_REBASE: &str = "rebase";
+const REBASING_TOKEN_TOTAL_SUPPLY: u64 = 1_000;
+const REBASING_TRANSFER_AMOUNT: u64 = 300;
+const ARG_SNAPSHOTS: &str = "snapshots";
+const ARG_VOTES: &str = "votes";
+
+fn make_rebasing_install_request(snapshots: bool, votes: bool) -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_REBASING_TEST,
+        runtime_args! {
+            ARG_SNAPSHOTS => snapshots,
+            ARG_VOTES => votes,
+        },
+    )
+    .build()
+}
+
+/// Installs a rebasing token and splits its supply between the default account, which keeps 400
+/// tokens, and accounts 1 and 2, which get 300 tokens each.
+fn setup_rebasing() -> (InMemoryWasmTestBuilder, ContractHash) {
+    let (mut builder, _) = setup();
+
+    let install_request = make_rebasing_install_request(false, false);
+    builder.exec(install_request).expect_success().commit();
+
+    let rebasing_token = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(REBASING_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have contract hash");
+
+    for recipient in &[*ACCOUNT_1_ADDR, *ACCOUNT_2_ADDR] {
+        let transfer_request = make_erc20_transfer_request(
+            Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            &rebasing_token,
+            Key::Account(*recipient),
+            U256::from(REBASING_TRANSFER_AMOUNT),
+        );
+        builder.exec(transfer_request).expect_success().commit();
+    }
+
+    (builder, rebasing_token)
+}
+
+fn make_erc20_rebase_request(
+    sender: AccountHash,
+    erc20_token: &ContractHash,
+    total_supply: U256,
+) -> ExecuteRequest {
+    make_erc20_request(
+        sender,
+        erc20_token,
+        METHOD_REBASE,
+        runtime_args! {
+            ARG_TOTAL_SUPPLY => total_supply,
+        },
+    )
+}
+
+/// Returns balances of the default account, account 1 and account 2.
+fn rebasing_balances(
+    builder: &mut InMemoryWasmTestBuilder,
+    erc20_token: &ContractHash,
+) -> [U256; 3] {
+    [
+        erc20_check_balance_of(builder, erc20_token, Key::Account(*DEFAULT_ACCOUNT_ADDR)),
+        erc20_check_balance_of(builder, erc20_token, Key::Account(*ACCOUNT_1_ADDR)),
+        erc20_check_balance_of(builder, erc20_token, Key::Account(*ACCOUNT_2_ADDR)),
+    ]
+}
+
+#[test]
+fn should_rebase_balances_proportionally() {
+    let (mut builder, rebasing_token) = setup_rebasing();
+
+    let events_count_before: u64 = builder.get_value(rebasing_token, EVENTS_COUNT_KEY);
+
+    let rebase_request = make_erc20_rebase_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &rebasing_token,
+        U256::from(REBASING_TOKEN_TOTAL_SUPPLY * 2),
+    );
+    builder.exec(rebase_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_total_supply(&mut builder, &rebasing_token),
+        U256::from(REBASING_TOKEN_TOTAL_SUPPLY * 2)
+    );
+
+    let events_count_after: u64 = builder.get_value(rebasing_token, EVENTS_COUNT_KEY);
+    assert_eq!(events_count_after, events_count_before + 1);
+
+    let rebase_event: BTreeMap<String, String> = erc20_get_dictionary_value(
+        &builder,
+        &rebasing_token,
+        EVENTS_KEY,
+        &events_count_before.to_string(),
+    );
+    assert_eq!(rebase_event["event_type"], "rebase");
+    assert_eq!(
+        rebase_event["previous_total_supply"],
+        REBASING_TOKEN_TOTAL_SUPPLY.to_string()
+    );
+    assert_eq!(
+        rebase_event["new_total_supply"],
+        (REBASING_TOKEN_TOTAL_SUPPLY * 2).to_string()
+    );
+    assert_eq!(
+        rebasing_balances(&mut builder, &rebasing_token),
+        [U256::from(800), U256::from(600), U256::from(600)]
+    );
+
+    // Transfers keep their meaning in tokens after a rebase.
+    let transfer_request = make_erc20_transfer_request(
+        Key::Account(*ACCOUNT_1_ADDR),
+        &rebasing_token,
+        Key::Account(*ACCOUNT_2_ADDR),
+        U256::from(100),
+    );
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        rebasing_balances(&mut builder, &rebasing_token),
+        [U256::from(800), U256::from(500), U256::from(700)]
+    );
+}
+
+#[test]
+fn should_round_rebased_balances_down() {
+    let (mut builder, rebasing_token) = setup_rebasing();
+
+    let total_supply = U256::from(REBASING_TOKEN_TOTAL_SUPPLY + 1);
+    let rebase_request =
+        make_erc20_rebase_request(*DEFAULT_ACCOUNT_ADDR, &rebasing_token, total_supply);
+    builder.exec(rebase_request).expect_success().commit();
+
+    // 400.4, 300.3 and 300.3 tokens are all rounded down.
+    let balances = rebasing_balances(&mut builder, &rebasing_token);
+    assert_eq!(
+        balances,
+        [U256::from(400), U256::from(300), U256::from(300)]
+    );
+    assert!(
+        balances
+            .iter()
+            .fold(U256::zero(), |sum, balance| sum + balance)
+            <= total_supply
+    );
+
+    // Whole rounded balance can be transferred, as it is taken out of shares rounding up.
+    let transfer_request = make_erc20_transfer_request(
+        Key::Account(*ACCOUNT_1_ADDR),
+        &rebasing_token,
+        Key::Account(*ACCOUNT_2_ADDR),
+        balances[1],
+    );
+    builder.exec(transfer_request).expect_success().commit();
+
+    let balances = rebasing_balances(&mut builder, &rebasing_token);
+    assert_eq!(balances, [U256::from(400), U256::zero(), U256::from(600)]);
+    assert!(
+        balances
+            .iter()
+            .fold(U256::zero(), |sum, balance| sum + balance)
+            <= total_supply
+    );
+
+    // A single minted token is worth less than a share, so it can not be given to anyone without
+    // taking value from the other holders.
+    let mint_request = make_erc20_mint_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &rebasing_token,
+        Key::Account(*ACCOUNT_1_ADDR),
+        U256::one(),
+    );
+    builder.exec(mint_request).expect_success().commit();
+
+    let total_supply = erc20_check_total_supply(&mut builder, &rebasing_token);
+    assert_eq!(total_supply, U256::from(REBASING_TOKEN_TOTAL_SUPPLY + 2));
+
+    let balances = rebasing_balances(&mut builder, &rebasing_token);
+    assert_eq!(balances, [U256::from(400), U256::zero(), U256::from(601)]);
+    assert!(
+        balances
+            .iter()
+            .fold(U256::zero(), |sum, balance| sum + balance)
+            <= total_supply
+    );
+}
+
+#[test]
+fn should_only_allow_admin_to_rebase_to_non_zero_supply() {
+    let (mut builder, rebasing_token) = setup_rebasing();
+
+    let rebase_request = make_erc20_rebase_request(
+        *ACCOUNT_1_ADDR,
+        &rebasing_token,
+        U256::from(REBASING_TOKEN_TOTAL_SUPPLY * 2),
+    );
+    builder.exec(rebase_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_MISSING_ROLE),
+        "{:?}",
+        error
+    );
+
+    let rebase_request =
+        make_erc20_rebase_request(*DEFAULT_ACCOUNT_ADDR, &rebasing_token, U256::zero());
+    builder.exec(rebase_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_REBASE),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_rebase_supply_without_shares() {
+    let (mut builder, rebasing_token) = setup_rebasing();
+
+    let holders = [*DEFAULT_ACCOUNT_ADDR, *ACCOUNT_1_ADDR, *ACCOUNT_2_ADDR];
+    let balances = rebasing_balances(&mut builder, &rebasing_token);
+    for (holder, balance) in holders.iter().zip(balances.iter()) {
+        let burn_request = make_erc20_request(
+            *DEFAULT_ACCOUNT_ADDR,
+            &rebasing_token,
+            METHOD_BURN,
+            runtime_args! {
+                ARG_OWNER => Key::Account(*holder),
+                ARG_AMOUNT => *balance,
+            },
+        );
+        builder.exec(burn_request).expect_success().commit();
+    }
+    assert_eq!(
+        erc20_check_total_supply(&mut builder, &rebasing_token),
+        U256::zero()
+    );
+
+    let rebase_request = make_erc20_rebase_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &rebasing_token,
+        U256::from(REBASING_TOKEN_TOTAL_SUPPLY),
+    );
+    builder.exec(rebase_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_REBASE),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_install_rebasing_with_snapshots_or_votes() {
+    let (mut builder, _) = setup();
+
+    for (snapshots, votes) in [(true, false), (false, true), (true, true)] {
+        let install_request = make_rebasing_install_request(snapshots, votes);
+        builder.exec(install_request).commit();
+
+        let error = builder.get_error().expect("should have error");
+        assert!(
+            matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_REBASING_NOT_SUPPORTED),
+            "{:?}",
+            error
+        );
+    }
+
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    assert!(!account
+        .named_keys()
+        .contains_key(REBASING_TEST_CONTRACT_KEY));
+}
@@ -0,0 +1,183 @@
+use super::*;
+
+fn erc20_check_balance_of_at(
+    builder: &mut InMemoryWasmTestBuilder,
+    erc20_contract_hash: &ContractHash,
+    erc20_test_call: ContractPackageHash,
+    address: Key,
+    snapshot_id: u64,
+) -> U256 {
+    let exec_request = make_erc20_check_at_request(
+        CHECK_BALANCE_OF_AT_ENTRYPOINT,
+        erc20_test_call,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => *erc20_contract_hash,
+            ARG_ADDRESS => address,
+            ARG_SNAPSHOT_ID => snapshot_id,
+        },
+    );
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, erc20_test_call)
+}
+
+fn erc20_check_total_supply_at(
+    builder: &mut InMemoryWasmTestBuilder,
+    erc20_contract_hash: &ContractHash,
+    erc20_test_call: ContractPackageHash,
+    snapshot_id: u64,
+) -> U256 {
+    let exec_request = make_erc20_check_at_request(
+        CHECK_TOTAL_SUPPLY_AT_ENTRYPOINT,
+        erc20_test_call,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => *erc20_contract_hash,
+            ARG_SNAPSHOT_ID => snapshot_id,
+        },
+    );
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, erc20_test_call)
+}
+
+#[test]
+fn should_read_balances_and_total_supply_at_snapshot() {
+    let (
+        mut builder,
+        TestContext {
+            test_contract,
+            erc20_test_call,
+            ..
+        },
+    ) = setup();
+
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_1_ADDR);
+    let transfer_amount = U256::from(TRANSFER_AMOUNT_1);
+    let mint_amount = U256::from(TRANSFER_AMOUNT_2);
+
+    let sender_balance = erc20_check_balance_of(&mut builder, &test_contract, sender);
+    let total_supply = erc20_check_total_supply(&mut builder, &test_contract);
+
+    let snapshot_request = make_erc20_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_SNAPSHOT,
+        RuntimeArgs::default(),
+    );
+    builder.exec(snapshot_request).expect_success().commit();
+
+    let transfer_request =
+        make_erc20_transfer_request(sender, &test_contract, recipient, transfer_amount);
+    builder.exec(transfer_request).expect_success().commit();
+
+    let mint_request = make_erc20_mint_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        recipient,
+        mint_amount,
+    );
+    builder.exec(mint_request).expect_success().commit();
+
+    let snapshot_request = make_erc20_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_SNAPSHOT,
+        RuntimeArgs::default(),
+    );
+    builder.exec(snapshot_request).expect_success().commit();
+
+    let transfer_request =
+        make_erc20_transfer_request(sender, &test_contract, recipient, transfer_amount);
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_balance_of_at(&mut builder, &test_contract, erc20_test_call, sender, 1),
+        sender_balance
+    );
+    assert_eq!(
+        erc20_check_balance_of_at(&mut builder, &test_contract, erc20_test_call, recipient, 1),
+        U256::zero()
+    );
+    assert_eq!(
+        erc20_check_balance_of_at(&mut builder, &test_contract, erc20_test_call, sender, 2),
+        sender_balance - transfer_amount
+    );
+    assert_eq!(
+        erc20_check_balance_of_at(&mut builder, &test_contract, erc20_test_call, recipient, 2),
+        transfer_amount + mint_amount
+    );
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, recipient),
+        transfer_amount + transfer_amount + mint_amount
+    );
+
+    assert_eq!(
+        erc20_check_total_supply_at(&mut builder, &test_contract, erc20_test_call, 1),
+        total_supply
+    );
+    assert_eq!(
+        erc20_check_total_supply_at(&mut builder, &test_contract, erc20_test_call, 2),
+        total_supply + mint_amount
+    );
+}
+
+#[test]
+fn should_not_read_balance_at_future_snapshot() {
+    let (
+        mut builder,
+        TestContext {
+            test_contract,
+            erc20_test_call,
+            ..
+        },
+    ) = setup();
+
+    let snapshot_request = make_erc20_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_SNAPSHOT,
+        RuntimeArgs::default(),
+    );
+    builder.exec(snapshot_request).expect_success().commit();
+
+    for snapshot_id in [0u64, 2].iter() {
+        let check_request = make_erc20_check_at_request(
+            CHECK_BALANCE_OF_AT_ENTRYPOINT,
+            erc20_test_call,
+            runtime_args! {
+                ARG_TOKEN_CONTRACT => test_contract,
+                ARG_ADDRESS => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+                ARG_SNAPSHOT_ID => *snapshot_id,
+            },
+        );
+        builder.exec(check_request).commit();
+
+        let error = builder.get_error().expect("should have error");
+        assert!(
+            matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_SNAPSHOT_ID),
+            "{:?}",
+            error
+        );
+    }
+}
+
+#[test]
+fn should_only_allow_admin_to_snapshot() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let snapshot_request = make_erc20_request(
+        *ACCOUNT_1_ADDR,
+        &test_contract,
+        METHOD_SNAPSHOT,
+        RuntimeArgs::default(),
+    );
+    builder.exec(snapshot_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_MISSING_ROLE),
+        "{:?}",
+        error
+    );
+}
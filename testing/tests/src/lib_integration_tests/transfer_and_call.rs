@@ -0,0 +1,120 @@
+use super::*;
+
+#[test]
+fn should_transfer_and_call_receiver() {
+    let (
+        mut builder,
+        TestContext {
+            test_contract,
+            erc20_test_call,
+            ..
+        },
+    ) = setup();
+
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let receiver = Key::Hash(erc20_test_call.value());
+    let amount = U256::from(TRANSFER_AMOUNT_1);
+
+    let transfer_and_call_request = make_erc20_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_TRANSFER_AND_CALL,
+        runtime_args! {
+            ARG_RECIPIENT => receiver,
+            ARG_AMOUNT => amount,
+            ARG_DATA => Bytes::from(b"deposit".to_vec()),
+        },
+    );
+    builder
+        .exec(transfer_and_call_request)
+        .expect_success()
+        .commit();
+
+    let received: (Key, U256) = get_test_result(&mut builder, erc20_test_call);
+    assert_eq!(received, (sender, amount));
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, receiver),
+        amount
+    );
+
+    let transfer_and_call_request = make_erc20_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_TRANSFER_AND_CALL,
+        runtime_args! {
+            ARG_RECIPIENT => receiver,
+            ARG_AMOUNT => amount,
+            ARG_DATA => Bytes::from(REJECT_DATA.to_vec()),
+        },
+    );
+    builder.exec(transfer_and_call_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_RECEIVER_REJECTED),
+        "{:?}",
+        error
+    );
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, receiver),
+        amount
+    );
+}
+
+#[test]
+fn should_not_transfer_and_call_account() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let transfer_and_call_request = make_erc20_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_TRANSFER_AND_CALL,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => U256::from(TRANSFER_AMOUNT_1),
+            ARG_DATA => Bytes::new(),
+        },
+    );
+    builder.exec(transfer_and_call_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_RECEIVER_REJECTED),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_approve_and_call_spender() {
+    let (
+        mut builder,
+        TestContext {
+            test_contract,
+            erc20_test_call,
+            ..
+        },
+    ) = setup();
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = Key::Hash(erc20_test_call.value());
+    let amount = U256::from(ALLOWANCE_AMOUNT_1);
+
+    let approve_and_call_request = make_erc20_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_APPROVE_AND_CALL,
+        runtime_args! {
+            ARG_SPENDER => spender,
+            ARG_AMOUNT => amount,
+            ARG_DATA => Bytes::new(),
+        },
+    );
+    builder
+        .exec(approve_and_call_request)
+        .expect_success()
+        .commit();
+
+    let approved: (Key, U256) = get_test_result(&mut builder, erc20_test_call);
+    assert_eq!(approved, (owner, amount));
+}
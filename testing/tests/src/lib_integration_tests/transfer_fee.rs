@@ -0,0 +1,252 @@
+use super::*;
+
+const TOKEN_TREASURY_ADDRESS: Key = Key::Account(AccountHash::new([77; 32]));
+const MAX_TRANSFER_FEE_BASIS_POINTS: u16 = 1_000;
+
+fn make_erc20_set_transfer_fee_request(
+    sender: AccountHash,
+    erc20_token: &ContractHash,
+    basis_points: u16,
+    minimum: U256,
+) -> ExecuteRequest {
+    make_erc20_request(
+        sender,
+        erc20_token,
+        METHOD_SET_TRANSFER_FEE,
+        runtime_args! {
+            ARG_BASIS_POINTS => basis_points,
+            ARG_MINIMUM => minimum,
+        },
+    )
+}
+
+fn make_erc20_transfer_from_request(
+    sender: AccountHash,
+    erc20_token: &ContractHash,
+    owner: Key,
+    recipient: Key,
+    amount: U256,
+) -> ExecuteRequest {
+    make_erc20_request(
+        sender,
+        erc20_token,
+        METHOD_TRANSFER_FROM,
+        runtime_args! {
+            ARG_OWNER => owner,
+            ARG_RECIPIENT => recipient,
+            ARG_AMOUNT => amount,
+        },
+    )
+}
+
+#[test]
+fn should_charge_transfer_fee_to_treasury() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_1_ADDR);
+
+    let set_transfer_fee_request = make_erc20_set_transfer_fee_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        TRANSFER_FEE_BASIS_POINTS,
+        U256::from(TRANSFER_FEE_MINIMUM),
+    );
+    builder
+        .exec(set_transfer_fee_request)
+        .expect_success()
+        .commit();
+
+    // A percentage fee is charged on large transfers.
+    let transfer_amount = U256::from(TRANSFER_AMOUNT_1);
+    let fee = transfer_amount * U256::from(TRANSFER_FEE_BASIS_POINTS) / 10_000;
+    let transfer_request =
+        make_erc20_transfer_request(sender, &test_contract, recipient, transfer_amount);
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, recipient),
+        transfer_amount - fee
+    );
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, TOKEN_TREASURY_ADDRESS),
+        fee
+    );
+
+    // The minimum fee is charged on small transfers.
+    let small_amount = U256::from(TRANSFER_FEE_MINIMUM * 10);
+    let transfer_request =
+        make_erc20_transfer_request(sender, &test_contract, recipient, small_amount);
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, recipient),
+        transfer_amount - fee + small_amount - U256::from(TRANSFER_FEE_MINIMUM)
+    );
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, TOKEN_TREASURY_ADDRESS),
+        fee + U256::from(TRANSFER_FEE_MINIMUM)
+    );
+
+    // Exempt addresses pay no fees.
+    let set_fee_exempt_request = make_erc20_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_SET_FEE_EXEMPT,
+        runtime_args! {
+            ARG_ADDRESS => recipient,
+            ARG_EXEMPT => true,
+        },
+    );
+    builder
+        .exec(set_fee_exempt_request)
+        .expect_success()
+        .commit();
+
+    let recipient_balance = erc20_check_balance_of(&mut builder, &test_contract, recipient);
+    let transfer_request =
+        make_erc20_transfer_request(sender, &test_contract, recipient, transfer_amount);
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, recipient),
+        recipient_balance + transfer_amount
+    );
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, TOKEN_TREASURY_ADDRESS),
+        fee + U256::from(TRANSFER_FEE_MINIMUM)
+    );
+}
+
+#[test]
+fn should_spend_allowance_for_gross_amount_with_transfer_fee() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = Key::Account(*ACCOUNT_1_ADDR);
+    let recipient = Key::Account(*ACCOUNT_2_ADDR);
+    let amount = U256::from(TRANSFER_AMOUNT_1);
+    let fee = amount * U256::from(TRANSFER_FEE_BASIS_POINTS) / 10_000;
+
+    let set_transfer_fee_request = make_erc20_set_transfer_fee_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        TRANSFER_FEE_BASIS_POINTS,
+        U256::zero(),
+    );
+    builder
+        .exec(set_transfer_fee_request)
+        .expect_success()
+        .commit();
+
+    let approve_request = make_erc20_approve_request(owner, &test_contract, spender, amount);
+    builder.exec(approve_request).expect_success().commit();
+
+    let transfer_from_request =
+        make_erc20_transfer_from_request(*ACCOUNT_1_ADDR, &test_contract, owner, recipient, amount);
+    builder
+        .exec(transfer_from_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, recipient),
+        amount - fee
+    );
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, TOKEN_TREASURY_ADDRESS),
+        fee
+    );
+
+    // Fee was paid out of the allowance, so none of it is left.
+    let transfer_from_request = make_erc20_transfer_from_request(
+        *ACCOUNT_1_ADDR,
+        &test_contract,
+        owner,
+        recipient,
+        U256::one(),
+    );
+    builder.exec(transfer_from_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_ALLOWANCE),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_only_allow_admin_to_set_transfer_fee_up_to_maximum() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let set_transfer_fee_request = make_erc20_set_transfer_fee_request(
+        *ACCOUNT_1_ADDR,
+        &test_contract,
+        TRANSFER_FEE_BASIS_POINTS,
+        U256::zero(),
+    );
+    builder.exec(set_transfer_fee_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_MISSING_ROLE),
+        "{:?}",
+        error
+    );
+
+    let set_transfer_fee_request = make_erc20_set_transfer_fee_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        MAX_TRANSFER_FEE_BASIS_POINTS + 1,
+        U256::zero(),
+    );
+    builder.exec(set_transfer_fee_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_TRANSFER_FEE_TOO_HIGH),
+        "{:?}",
+        error
+    );
+
+    let set_transfer_fee_request = make_erc20_set_transfer_fee_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        MAX_TRANSFER_FEE_BASIS_POINTS,
+        U256::zero(),
+    );
+    builder
+        .exec(set_transfer_fee_request)
+        .expect_success()
+        .commit();
+
+    // An oversized minimum can not take more than the maximum out of a transfer.
+    let set_transfer_fee_request = make_erc20_set_transfer_fee_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        TRANSFER_FEE_BASIS_POINTS,
+        U256::from(TRANSFER_AMOUNT_1),
+    );
+    builder
+        .exec(set_transfer_fee_request)
+        .expect_success()
+        .commit();
+
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_1_ADDR);
+    let transfer_amount = U256::from(TRANSFER_AMOUNT_1);
+    let max_fee = transfer_amount * U256::from(MAX_TRANSFER_FEE_BASIS_POINTS) / 10_000;
+    let transfer_request =
+        make_erc20_transfer_request(sender, &test_contract, recipient, transfer_amount);
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, recipient),
+        transfer_amount - max_fee
+    );
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, TOKEN_TREASURY_ADDRESS),
+        max_fee
+    );
+}
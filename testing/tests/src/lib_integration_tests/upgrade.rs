@@ -0,0 +1,119 @@
+use super::*;
+
+const METHOD_FORGET_STORAGE_VERSION: &str = "forget_storage_version";
+
+#[test]
+fn should_upgrade_and_keep_balances() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_1_ADDR);
+    let transfer_amount = U256::from(TRANSFER_AMOUNT_1);
+
+    let transfer_request =
+        make_erc20_transfer_request(sender, &test_contract, recipient, transfer_amount);
+    builder.exec(transfer_request).expect_success().commit();
+
+    let total_supply = erc20_check_total_supply(&mut builder, &test_contract);
+
+    let upgrade_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_ERC20_TEST,
+        runtime_args! {
+            ARG_EVENTS_MODE => EVENTS_MODE_NATIVE,
+            ARG_UPGRADE => true,
+        },
+    )
+    .build();
+    builder.exec(upgrade_request).expect_success().commit();
+
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let upgraded_contract = account
+        .named_keys()
+        .get(TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have contract hash");
+    let contract_package_hash = account
+        .named_keys()
+        .get(TEST_CONTRACT_PACKAGE_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have contract package hash");
+    assert_ne!(upgraded_contract, test_contract);
+
+    let contract_package = builder
+        .get_contract_package(contract_package_hash)
+        .expect("should have contract package");
+    assert_eq!(contract_package.enabled_versions().len(), 1);
+
+    let storage_version: u32 = builder.get_value(upgraded_contract, STORAGE_VERSION_KEY);
+    assert_eq!(storage_version, 2);
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &upgraded_contract, recipient),
+        transfer_amount
+    );
+    assert_eq!(
+        erc20_check_total_supply(&mut builder, &upgraded_contract),
+        total_supply
+    );
+
+    // Previous version can no longer be called.
+    let transfer_request =
+        make_erc20_transfer_request(sender, &test_contract, recipient, transfer_amount);
+    builder.exec(transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::DisabledContract(contract_hash)) if contract_hash == test_contract),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_upgrade_incompatible_storage_version() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let forget_storage_version_request = make_erc20_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_FORGET_STORAGE_VERSION,
+        RuntimeArgs::default(),
+    );
+    builder
+        .exec(forget_storage_version_request)
+        .expect_success()
+        .commit();
+
+    let upgrade_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_ERC20_TEST,
+        runtime_args! {
+            ARG_EVENTS_MODE => EVENTS_MODE_NATIVE,
+            ARG_UPGRADE => true,
+        },
+    )
+    .build();
+    builder.exec(upgrade_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INCOMPATIBLE_STORAGE_VERSION),
+        "{:?}",
+        error
+    );
+
+    let test_contract_after: ContractHash = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have contract hash");
+    assert_eq!(test_contract_after, test_contract);
+}
@@ -0,0 +1,226 @@
+use super::*;
+
+const VESTING_CONTRACT_KEY: &str = "erc20_vesting_contract";
+const VESTING_CONTRACT_PACKAGE_KEY: &str = "erc20_vesting_contract_package";
+const METHOD_CREATE_SCHEDULE: &str = "create_schedule";
+const METHOD_RELEASE: &str = "release";
+const ARG_BENEFICIARY: &str = "beneficiary";
+const ARG_TOTAL: &str = "total";
+const ARG_START: &str = "start";
+const ARG_CLIFF: &str = "cliff";
+const ARG_DURATION: &str = "duration";
+const VESTING_SCHEDULES_KEY: &str = "schedules";
+const ERROR_VESTING_NOT_OWNER: u16 = 1;
+const ERROR_VESTING_INVALID_SCHEDULE: u16 = 3;
+const ERROR_VESTING_NOTHING_TO_RELEASE: u16 = 5;
+const VESTING_TOTAL: u64 = 1_000;
+const VESTING_START: u64 = 1_000;
+const VESTING_CLIFF: u64 = 1_000;
+const VESTING_DURATION: u64 = 4_000;
+
+fn install_vesting(
+    builder: &mut InMemoryWasmTestBuilder,
+    erc20_token: &ContractHash,
+) -> (ContractHash, ContractPackageHash) {
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        EXAMPLE_ERC20_VESTING,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => *erc20_token,
+        },
+    )
+    .build();
+    builder.exec(install_request).expect_success().commit();
+
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let vesting_contract = account
+        .named_keys()
+        .get(VESTING_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have contract hash");
+
+    let vesting_package = account
+        .named_keys()
+        .get(VESTING_CONTRACT_PACKAGE_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have contract package hash");
+
+    (vesting_contract, vesting_package)
+}
+
+fn make_vesting_create_schedule_request(
+    sender: AccountHash,
+    vesting_contract: &ContractHash,
+    beneficiary: Key,
+    total: U256,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        *vesting_contract,
+        METHOD_CREATE_SCHEDULE,
+        runtime_args! {
+            ARG_BENEFICIARY => beneficiary,
+            ARG_TOTAL => total,
+            ARG_START => VESTING_START,
+            ARG_CLIFF => VESTING_CLIFF,
+            ARG_DURATION => VESTING_DURATION,
+        },
+    )
+    .with_block_time(VESTING_START)
+    .build()
+}
+
+fn make_vesting_release_request(
+    sender: AccountHash,
+    vesting_contract: &ContractHash,
+    block_time: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        *vesting_contract,
+        METHOD_RELEASE,
+        RuntimeArgs::default(),
+    )
+    .with_block_time(block_time)
+    .build()
+}
+
+#[test]
+fn should_release_vested_tokens_linearly_after_cliff() {
+    let (mut builder, TestContext { erc20_token, .. }) = setup();
+    let (vesting_contract, vesting_package) = install_vesting(&mut builder, &erc20_token);
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let beneficiary = Key::Account(*ACCOUNT_1_ADDR);
+    let vesting = Key::Hash(vesting_package.value());
+
+    let approve_request =
+        make_erc20_approve_request(owner, &erc20_token, vesting, U256::from(VESTING_TOTAL));
+    builder.exec(approve_request).expect_success().commit();
+
+    let create_schedule_request = make_vesting_create_schedule_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &vesting_contract,
+        beneficiary,
+        U256::from(VESTING_TOTAL),
+    );
+    builder
+        .exec(create_schedule_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &erc20_token, vesting),
+        U256::from(VESTING_TOTAL)
+    );
+
+    let schedule: ((U256, U256), (u64, u64), u64) = erc20_get_dictionary_value(
+        &builder,
+        &vesting_contract,
+        VESTING_SCHEDULES_KEY,
+        &base64::encode(&beneficiary.to_bytes().unwrap()),
+    );
+    assert_eq!(
+        schedule,
+        (
+            (U256::from(VESTING_TOTAL), U256::zero()),
+            (VESTING_START, VESTING_CLIFF),
+            VESTING_DURATION
+        )
+    );
+
+    // Nothing can be released before the cliff.
+    let release_request = make_vesting_release_request(
+        *ACCOUNT_1_ADDR,
+        &vesting_contract,
+        VESTING_START + VESTING_CLIFF - 1,
+    );
+    builder.exec(release_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_VESTING_NOTHING_TO_RELEASE),
+        "{:?}",
+        error
+    );
+
+    // Half of the tokens are vested halfway through the schedule.
+    let release_request = make_vesting_release_request(
+        *ACCOUNT_1_ADDR,
+        &vesting_contract,
+        VESTING_START + VESTING_DURATION / 2,
+    );
+    builder.exec(release_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &erc20_token, beneficiary),
+        U256::from(VESTING_TOTAL / 2)
+    );
+
+    let release_request = make_vesting_release_request(
+        *ACCOUNT_1_ADDR,
+        &vesting_contract,
+        VESTING_START + VESTING_DURATION,
+    );
+    builder.exec(release_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &erc20_token, beneficiary),
+        U256::from(VESTING_TOTAL)
+    );
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &erc20_token, vesting),
+        U256::zero()
+    );
+}
+
+#[test]
+fn should_only_allow_owner_to_create_vesting_schedules() {
+    let (mut builder, TestContext { erc20_token, .. }) = setup();
+    let (vesting_contract, _) = install_vesting(&mut builder, &erc20_token);
+
+    let beneficiary = Key::Account(*ACCOUNT_2_ADDR);
+
+    let create_schedule_request = make_vesting_create_schedule_request(
+        *ACCOUNT_1_ADDR,
+        &vesting_contract,
+        beneficiary,
+        U256::from(VESTING_TOTAL),
+    );
+    builder.exec(create_schedule_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_VESTING_NOT_OWNER),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_create_vesting_schedules_without_tokens() {
+    let (mut builder, TestContext { erc20_token, .. }) = setup();
+    let (vesting_contract, _) = install_vesting(&mut builder, &erc20_token);
+
+    let beneficiary = Key::Account(*ACCOUNT_1_ADDR);
+
+    let create_schedule_request = make_vesting_create_schedule_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &vesting_contract,
+        beneficiary,
+        U256::zero(),
+    );
+    builder.exec(create_schedule_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_VESTING_INVALID_SCHEDULE),
+        "{:?}",
+        error
+    );
+}
@@ -0,0 +1,102 @@
+use super::*;
+
+#[test]
+fn should_checkpoint_delegated_votes() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let delegator = Key::Account(*ACCOUNT_1_ADDR);
+    let delegatee = Key::Account(*ACCOUNT_2_ADDR);
+    let transfer_amount_1 = U256::from(TRANSFER_AMOUNT_1);
+    let transfer_amount_2 = U256::from(TRANSFER_AMOUNT_2);
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENT => delegator,
+            ARG_AMOUNT => transfer_amount_1,
+        },
+    )
+    .with_block_time(1_000)
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+
+    let delegate_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        test_contract,
+        METHOD_DELEGATE,
+        runtime_args! {
+            ARG_DELEGATEE => delegatee,
+        },
+    )
+    .with_block_time(2_000)
+    .build();
+    builder.exec(delegate_request).expect_success().commit();
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENT => delegator,
+            ARG_AMOUNT => transfer_amount_2,
+        },
+    )
+    .with_block_time(3_000)
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+
+    let delegator_key = base64::encode(&delegator.to_bytes().unwrap());
+    let delegatee_key = base64::encode(&delegatee.to_bytes().unwrap());
+
+    let delegate: Key =
+        erc20_get_dictionary_value(&builder, &test_contract, DELEGATES_KEY, &delegator_key);
+    assert_eq!(delegate, delegatee);
+
+    let checkpoints: Vec<(u64, U256)> =
+        erc20_get_dictionary_value(&builder, &test_contract, VOTES_KEY, &delegatee_key);
+    assert_eq!(
+        checkpoints,
+        vec![
+            (2_000, transfer_amount_1),
+            (3_000, transfer_amount_1 + transfer_amount_2)
+        ]
+    );
+
+    let get_past_votes_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_GET_PAST_VOTES,
+        runtime_args! {
+            ARG_ACCOUNT => delegatee,
+            ARG_BLOCK_TIME => 2_500u64,
+        },
+    )
+    .with_block_time(3_000)
+    .build();
+    builder
+        .exec(get_past_votes_request)
+        .expect_success()
+        .commit();
+
+    let get_past_votes_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_GET_PAST_VOTES,
+        runtime_args! {
+            ARG_ACCOUNT => delegatee,
+            ARG_BLOCK_TIME => 3_000u64,
+        },
+    )
+    .with_block_time(3_000)
+    .build();
+    builder.exec(get_past_votes_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_FUTURE_LOOKUP),
+        "{:?}",
+        error
+    );
+}
@@ -0,0 +1,186 @@
+use super::*;
+
+const WCSPR_TEST_CONTRACT_KEY: &str = "wcspr_test_contract";
+const WRAPPED_PURSE_KEY: &str = "wrapped_purse";
+const METHOD_WITHDRAW: &str = "withdraw";
+const WRAPPED_DECIMALS: u8 = 9;
+const DEPOSIT_AMOUNT: u64 = 5_000_000_000;
+const WITHDRAW_AMOUNT: u64 = 2_000_000_000;
+
+fn install_wcspr(builder: &mut InMemoryWasmTestBuilder) -> ContractHash {
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_WCSPR_TEST,
+        runtime_args! {
+            ARG_DECIMALS => WRAPPED_DECIMALS,
+            ARG_TOTAL_SUPPLY => U256::zero(),
+        },
+    )
+    .build();
+    builder.exec(install_request).expect_success().commit();
+
+    builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(WCSPR_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have contract hash")
+}
+
+fn wcspr_purse_balance(builder: &mut InMemoryWasmTestBuilder, wcspr: &ContractHash) -> U512 {
+    let wrapped_purse: URef = builder
+        .get_contract(*wcspr)
+        .expect("should have contract")
+        .named_keys()
+        .get(WRAPPED_PURSE_KEY)
+        .and_then(|key| key.into_uref())
+        .expect("should have wrapped purse");
+    builder.get_purse_balance(wrapped_purse)
+}
+
+fn make_wcspr_deposit_request(
+    sender: AccountHash,
+    wcspr: &ContractHash,
+    amount: U512,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        sender,
+        SESSION_WCSPR_DEPOSIT,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => *wcspr,
+            ARG_AMOUNT => amount,
+        },
+    )
+    .build()
+}
+
+fn make_wcspr_withdraw_request(
+    sender: AccountHash,
+    wcspr: &ContractHash,
+    amount: U256,
+) -> ExecuteRequest {
+    make_erc20_request(
+        sender,
+        wcspr,
+        METHOD_WITHDRAW,
+        runtime_args! {
+            ARG_AMOUNT => amount,
+        },
+    )
+}
+
+#[test]
+fn should_deposit_and_withdraw_wrapped_cspr() {
+    let (mut builder, _) = setup();
+    let wcspr = install_wcspr(&mut builder);
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    let deposit_request =
+        make_wcspr_deposit_request(*DEFAULT_ACCOUNT_ADDR, &wcspr, U512::from(DEPOSIT_AMOUNT));
+    builder.exec(deposit_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &wcspr, owner),
+        U256::from(DEPOSIT_AMOUNT)
+    );
+    assert_eq!(
+        erc20_check_total_supply(&mut builder, &wcspr),
+        U256::from(DEPOSIT_AMOUNT)
+    );
+    assert_eq!(
+        wcspr_purse_balance(&mut builder, &wcspr),
+        U512::from(DEPOSIT_AMOUNT)
+    );
+
+    let withdraw_request =
+        make_wcspr_withdraw_request(*DEFAULT_ACCOUNT_ADDR, &wcspr, U256::from(WITHDRAW_AMOUNT));
+    builder.exec(withdraw_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &wcspr, owner),
+        U256::from(DEPOSIT_AMOUNT - WITHDRAW_AMOUNT)
+    );
+    assert_eq!(
+        erc20_check_total_supply(&mut builder, &wcspr),
+        U256::from(DEPOSIT_AMOUNT - WITHDRAW_AMOUNT)
+    );
+    assert_eq!(
+        wcspr_purse_balance(&mut builder, &wcspr),
+        U512::from(DEPOSIT_AMOUNT - WITHDRAW_AMOUNT)
+    );
+}
+
+#[test]
+fn should_not_withdraw_more_than_deposited() {
+    let (mut builder, _) = setup();
+    let wcspr = install_wcspr(&mut builder);
+
+    let deposit_request =
+        make_wcspr_deposit_request(*DEFAULT_ACCOUNT_ADDR, &wcspr, U512::from(DEPOSIT_AMOUNT));
+    builder.exec(deposit_request).expect_success().commit();
+
+    let withdraw_request =
+        make_wcspr_withdraw_request(*ACCOUNT_1_ADDR, &wcspr, U256::from(WITHDRAW_AMOUNT));
+    builder.exec(withdraw_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_BALANCE),
+        "{:?}",
+        error
+    );
+
+    assert_eq!(
+        wcspr_purse_balance(&mut builder, &wcspr),
+        U512::from(DEPOSIT_AMOUNT)
+    );
+}
+
+#[test]
+fn should_not_install_wrapped_cspr_with_other_decimals() {
+    let (mut builder, _) = setup();
+
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_WCSPR_TEST,
+        runtime_args! {
+            ARG_DECIMALS => TOKEN_DECIMALS,
+            ARG_TOTAL_SUPPLY => U256::zero(),
+        },
+    )
+    .build();
+    builder.exec(install_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_DECIMALS),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_install_wrapped_cspr_with_initial_supply() {
+    let (mut builder, _) = setup();
+
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_WCSPR_TEST,
+        runtime_args! {
+            ARG_DECIMALS => WRAPPED_DECIMALS,
+            ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        },
+    )
+    .build();
+    builder.exec(install_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_UNBACKED_SUPPLY),
+        "{:?}",
+        error
+    );
+}
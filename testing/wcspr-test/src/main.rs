@@ -0,0 +1,87 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::string::ToString;
+
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_erc20::{
+    constants::{
+        ADDRESS_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME, DECIMALS_RUNTIME_ARG_NAME,
+        PURSE_RUNTIME_ARG_NAME, RECIPIENT_RUNTIME_ARG_NAME, TOTAL_SUPPLY_RUNTIME_ARG_NAME,
+    },
+    Address, InstallOptions, ERC20,
+};
+use casper_types::{CLValue, EntryPoints, URef, U256, U512};
+
+const WCSPR_TEST_CONTRACT_KEY_NAME: &str = "wcspr_test_contract";
+const TOKEN_NAME: &str = "Wrapped CSPR";
+const TOKEN_SYMBOL: &str = "WCSPR";
+
+fn entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(casper_erc20::entry_points::total_supply());
+    entry_points.add_entry_point(casper_erc20::entry_points::balance_of());
+    entry_points.add_entry_point(casper_erc20::entry_points::transfer());
+    entry_points.add_entry_point(casper_erc20::entry_points::deposit());
+    entry_points.add_entry_point(casper_erc20::entry_points::withdraw());
+    entry_points
+}
+
+#[no_mangle]
+pub extern "C" fn total_supply() {
+    let val = ERC20::default().total_supply();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn balance_of() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let val = ERC20::default().balance_of(address);
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn transfer() {
+    let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    ERC20::default()
+        .transfer(recipient, amount)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn deposit() {
+    let purse: URef = runtime::get_named_arg(PURSE_RUNTIME_ARG_NAME);
+    let amount: U512 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    ERC20::default().deposit(purse, amount).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn withdraw() {
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    ERC20::default().withdraw(amount).unwrap_or_revert();
+}
+
+#[no_mangle]
+fn call() {
+    // Decimals and initial supply are passed in to check that only the decimals of motes and no
+    // initial supply are accepted.
+    let decimals: u8 = runtime::get_named_arg(DECIMALS_RUNTIME_ARG_NAME);
+    let initial_supply: U256 = runtime::get_named_arg(TOTAL_SUPPLY_RUNTIME_ARG_NAME);
+
+    ERC20::install_custom(
+        TOKEN_NAME.to_string(),
+        TOKEN_SYMBOL.to_string(),
+        decimals,
+        initial_supply,
+        WCSPR_TEST_CONTRACT_KEY_NAME,
+        entry_points(),
+        InstallOptions {
+            wrapped: true,
+            ..InstallOptions::default()
+        },
+    )
+    .unwrap_or_revert();
+}